@@ -4,33 +4,29 @@
 extern crate alloc;
 
 mod strip;
-mod map;
-mod commands;
-mod animations;
-mod constants;
 
 use alloc::boxed::Box;
-use embedded_hal::serial::{Read, Write};
-use embedded_hal::timer::CountDown;
 use esp_backtrace as _;
-use esp_println::println;
 use hal::{clock::ClockControl, peripherals::Peripherals, prelude::*, timer::{TimerGroup}, Rtc, IO, Delay, PulseControl, Uart};
 use hal::uart::config::{Config, DataBits, Parity, StopBits};
 use hal::uart::TxRxPins;
-use nb::block;
-use nb::Error::{Other};
 use smart_leds::{RGB8, SmartLedsWrite};
 use esp_alloc::EspHeap;
-use crate::animations::animation_manager::AnimationManager;
-use crate::commands::all_command::AllCommand;
-use crate::commands::command_handler::{CommandHandler};
-use crate::commands::command_handler;
-use crate::commands::hello_world_command::HelloWorldCommand;
-use crate::commands::reset_command::ResetCommand;
-use crate::commands::set_command::SetCommand;
-use crate::commands::snake_command::SnakeCommand;
-use crate::map::Map;
-use crate::strip::StripTiming;
+use esp_storage::FlashStorage as EspFlash;
+use map_core::animations::animation_manager::AnimationManager;
+use map_core::commands::command_handler::CommandHandler;
+use map_core::{commands, config, console, constants, map};
+use map_core::map::layout;
+use map_core::output::OutputSettings;
+use map_core::scenes::Scenes;
+use map_core::storage::flash_storage::FlashStorage;
+use map_core::storage::memory_storage::MemoryStorage;
+use map_core::storage::Storage;
+use map_core::strip::StripTiming;
+
+/// The storage takes the nvs partition of the default partition table.
+const STORAGE_OFFSET: u32 = 0x9000;
+const STORAGE_SECTORS: usize = 6;
 
 #[global_allocator]
 static ALLOCATOR: EspHeap = EspHeap::empty();
@@ -76,6 +72,12 @@ fn main() -> ! {
 
     let io = IO::new(peripherals.GPIO, peripherals.IO_MUX);
 
+    // Init storage, the configuration is kept until restart if the flash cannot be used
+    let mut storage: Box<dyn Storage> = match FlashStorage::new(EspFlash::new(), STORAGE_OFFSET, STORAGE_SECTORS) {
+        Ok(storage) => Box::new(storage),
+        Err(_) => Box::new(MemoryStorage::new()),
+    };
+
     // Init UART
     let pins = TxRxPins::new_tx_rx(
         io.pins.gpio1.into_push_pull_output(),
@@ -83,7 +85,7 @@ fn main() -> ! {
     );
 
     let config = Config {
-        baudrate: 115200,
+        baudrate: config::baud_rate(storage.as_ref()),
         data_bits: DataBits::DataBits8,
         parity: Parity::ParityNone,
         stop_bits: StopBits::STOP1,
@@ -115,24 +117,21 @@ fn main() -> ! {
     );
 
     // Init map
-    let mut rgb_data: [RGB8; 72] = [RGB8 { r: 0, g: 0, b: 0 }; 72];
-    let mut map = map::Map::new(&map::INDEX_MAP, &mut rgb_data);
+    let mut rgb_data: [RGB8; constants::LEDS_COUNT] = [RGB8 { r: 0, g: 0, b: 0 }; constants::LEDS_COUNT];
+    let mut map = map::Map::new(&layout::LAYOUT, &mut rgb_data);
+    let mut settings = OutputSettings::new();
+    let mut scenes = Scenes::new();
+    config::load(storage.as_ref(), &mut map, &mut settings, &mut scenes);
     let mut animations = AnimationManager::new(timer_group0.timer0);
     let mut delay = Delay::new(&clocks);
 
     // Init commands
     let mut handler = CommandHandler::new(
-        [
-            ("HELLO_WORLD", Box::new(HelloWorldCommand::default())),
-            ("SET", Box::new(SetCommand::default())),
-            ("RESET", Box::new(ResetCommand::default())),
-            ("ALL", Box::new(AllCommand::default())),
-            ("SNAKE", Box::new(SnakeCommand::default()))
-        ],
+        commands::handlers(),
         ['\0'; constants::COMMAND_BUFFER],
     );
 
-    print_new_command(&mut serial);
+    console::print_new_command(&mut serial);
 
     loop {
         // result is either ok, then do nothing,
@@ -140,49 +139,9 @@ fn main() -> ! {
         // or last step, then do nothing as well...
         let _ = animations.update(&mut map);
 
-        let new_command = match handler.read_command(&mut serial) {
-            Ok(()) => {
-                println!("\r");
-                let result = handler.handle_command(&mut map, animations.storage());
-
-                match result {
-                    Ok(()) => true,
-                    Err(err) => {
-                        match err {
-                            command_handler::CommandHandleError::NotFound => println!("Command not found.\r"),
-                            command_handler::CommandHandleError::WrongArguments => println!("Wrong arguments.\r"),
-                            command_handler::CommandHandleError::CommandNotRead => println!("FATAL: Command is not prepared.\r")
-                        }
-                        true
-                    }
-                }
-            },
-            Err(err) => {
-                match err {
-                    Other(error) => {
-                        match error {
-                            command_handler::CommandReadError::BufferOverflowed => println!("Command is too long.\r"),
-                            command_handler::CommandReadError::UnexpectedEndOfLine => (),
-                            command_handler::CommandReadError::CommandLoadedAlready => println!("FATAL: Previous command not processed correctly.\r")
-                        };
-                        true
-                    }
-                    _ => false
-                }
-            }
-        };
-
-        if new_command {
-            print_new_command(&mut serial);
-        }
-
-        strip.write(map.get_map().cloned()).unwrap();
-        delay.delay_us(500u32);
-    }
+        console::poll(&mut handler, &mut serial, &mut map, animations.storage(), &mut settings, &mut scenes, storage.as_mut());
 
-    fn print_new_command<T: Write<u8>>(serial: &mut T) {
-        println!("\r");
-        block!(serial.write(b'>')).ok().unwrap();
-        block!(serial.write(b' ')).ok().unwrap();
+        strip.write(settings.apply(map.frame())).unwrap();
+        delay.delay_us(500u32);
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,59 @@
+use hal::gpio::{OutputPin};
+use hal::peripheral::Peripheral;
+use hal::pulse_control::{OutputChannel, ConfiguredChannel, PulseCode, RepeatMode, TransmissionError, ClockSource};
+use smart_leds::{RGB8, SmartLedsWrite};
+use fugit::NanosDuration;
+use map_core::strip::{self, StripTiming};
+
+pub struct Strip<CHANNEL, const COUNT: usize> {
+    channel: CHANNEL,
+    timing: StripTiming
+}
+
+impl<'d, CHANNEL, const COUNT: usize> Strip<CHANNEL, COUNT>
+    where CHANNEL: ConfiguredChannel
+{
+    pub fn new<P: OutputPin + 'd, UnconfiguredChannel>(mut channel: UnconfiguredChannel, pin: impl Peripheral<P=P> + 'd, timing: StripTiming) -> Self
+        where UnconfiguredChannel: OutputChannel<ConfiguredChannel<'d, P> = CHANNEL>
+    {
+        channel
+            .set_channel_divider(4) // 1 tick = 50 ns = 0.05 us
+            .set_carrier_modulation(false)
+            .set_idle_output(true)
+            .set_idle_output_level(false)
+            .set_clock_source(ClockSource::APB);
+
+        let channel = channel.assign_pin(pin);
+
+        Strip::<CHANNEL, COUNT> {
+            channel,
+            timing
+        }
+    }
+
+    fn bit_to_pulse_code(&self, bit: bool) -> u32 {
+        let length1 = NanosDuration::<u32>::from_ticks(self.timing.high_duration(bit).ticks() / 50);
+        let length2 = NanosDuration::<u32>::from_ticks(self.timing.low_duration(bit).ticks() / 50);
+
+        PulseCode {
+            level1: true,
+            length1,
+            level2: false,
+            length2,
+        }.into()
+    }
+}
+
+impl<CHANNEL, const COUNT: usize> SmartLedsWrite for Strip<CHANNEL, COUNT>
+    where CHANNEL: ConfiguredChannel
+{
+    type Error = TransmissionError;
+    type Color = RGB8;
+
+    fn write<T, I>(&mut self, iterator: T) -> Result<(), Self::Error> where T: Iterator<Item=I>, I: Into<Self::Color> {
+        let mut buffer: [u32; COUNT] = [0; COUNT];
+        strip::encode(iterator, &mut buffer, |bit| self.bit_to_pulse_code(bit));
+
+        self.channel.send_pulse_sequence_raw(RepeatMode::SingleShot, &buffer)
+    }
+}
@@ -0,0 +1,250 @@
+//! Compiles the board layout into `$OUT_DIR/layout.rs`.
+//!
+//! The layout is selected by the `MAP_LAYOUT` environment variable,
+//! either a name of a layout from the `layouts` directory (e.g. `czech`)
+//! or a path to a layout file. The `czech` layout is used by default.
+//! See `layouts/czech.layout` for the format.
+
+use std::env;
+use std::fmt::Write as FmtWrite;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+const DEFAULT_LAYOUT: &str = "czech";
+
+struct Region {
+    name: String,
+    aliases: Vec<String>,
+}
+
+struct District {
+    name: String,
+    region: usize,
+    latitude: String,
+    longitude: String,
+    aliases: Vec<String>,
+}
+
+#[derive(Default)]
+struct Layout {
+    name: Option<String>,
+    regions: Vec<Region>,
+    districts: Vec<District>,
+    borders: Vec<(usize, usize)>,
+    snake: Vec<usize>,
+}
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=MAP_LAYOUT");
+
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let layout = env::var("MAP_LAYOUT").unwrap_or_else(|_| DEFAULT_LAYOUT.to_string());
+    let path = layout_path(&manifest_dir, &layout);
+    println!("cargo:rerun-if-changed={}", path.display());
+
+    let source = fs::read_to_string(&path).unwrap_or_else(|err| {
+        fail(&format!("Could not read layout {}: {}", path.display(), err))
+    });
+
+    let layout = parse(&source).unwrap_or_else(|(line, err)| {
+        fail(&format!("{}:{}: {}", path.display(), line, err))
+    });
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(out_dir.join("layout.rs"), generate(&layout)).unwrap();
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+/// Plain names refer to the bundled layouts, anything else is a path
+/// relative to the map_core directory.
+fn layout_path(manifest_dir: &Path, layout: &str) -> PathBuf {
+    if layout.contains(['/', '\\', '.']) {
+        manifest_dir.join(layout)
+    } else {
+        manifest_dir.join("layouts").join(format!("{}.layout", layout))
+    }
+}
+
+fn parse(source: &str) -> Result<Layout, (usize, String)> {
+    let mut layout = Layout::default();
+
+    for (number, line) in source.lines().enumerate() {
+        let line = line.split('#').next().unwrap();
+        let mut tokens = line.split_whitespace();
+        let keyword = match tokens.next() {
+            Some(keyword) => keyword,
+            None => continue,
+        };
+        let tokens: Vec<&str> = tokens.collect();
+
+        parse_line(&mut layout, keyword, &tokens).map_err(|err| (number + 1, err))?;
+    }
+
+    if layout.districts.is_empty() {
+        return Err((0, "The layout has no districts.".to_string()));
+    }
+
+    if !layout.snake.is_empty() {
+        if layout.snake.len() != layout.districts.len() {
+            return Err((0, format!(
+                "The snake goes through {} districts, but there are {}.",
+                layout.snake.len(),
+                layout.districts.len()
+            )));
+        }
+
+        for (index, district) in layout.districts.iter().enumerate() {
+            if !layout.snake.contains(&index) {
+                return Err((0, format!("The snake does not go through {}.", district.name)));
+            }
+        }
+    }
+
+    Ok(layout)
+}
+
+fn parse_line(layout: &mut Layout, keyword: &str, tokens: &[&str]) -> Result<(), String> {
+    match keyword {
+        "name" => {
+            let name = tokens.first().ok_or("Missing name of the layout.")?;
+            layout.name = Some(name.replace('_', " "));
+        }
+        "region" => {
+            let name = tokens.first().ok_or("Missing name of the region.")?;
+            if layout.regions.iter().any(|region| region.name == *name) {
+                return Err(format!("Duplicate region {}.", name));
+            }
+
+            layout.regions.push(Region {
+                name: name.to_string(),
+                aliases: tokens[1..].iter().map(|alias| alias.to_string()).collect(),
+            });
+        }
+        "district" => {
+            if tokens.len() < 4 {
+                return Err("Expected district <NAME> <REGION> <LATITUDE> <LONGITUDE> [ALIAS...].".to_string());
+            }
+
+            let name = tokens[0];
+            if find_district(layout, name).is_ok() {
+                return Err(format!("Duplicate district {}.", name));
+            }
+
+            let region = layout.regions.iter()
+                .position(|region| region.name == tokens[1])
+                .ok_or_else(|| format!("Unknown region {}.", tokens[1]))?;
+
+            layout.districts.push(District {
+                name: name.to_string(),
+                region,
+                latitude: parse_coordinate(tokens[2], 90.0)?,
+                longitude: parse_coordinate(tokens[3], 180.0)?,
+                aliases: tokens[4..].iter().map(|alias| alias.to_string()).collect(),
+            });
+        }
+        "border" => {
+            if tokens.len() != 2 {
+                return Err("Expected border <DISTRICT> <DISTRICT>.".to_string());
+            }
+
+            let a = find_district(layout, tokens[0])?;
+            let b = find_district(layout, tokens[1])?;
+            if a == b {
+                return Err(format!("{} cannot border itself.", tokens[0]));
+            }
+
+            let border = (a.min(b), a.max(b));
+            if layout.borders.contains(&border) {
+                return Err(format!("Duplicate border {} - {}.", tokens[0], tokens[1]));
+            }
+            layout.borders.push(border);
+        }
+        "snake" => {
+            for name in tokens {
+                let index = find_district(layout, name)?;
+                if layout.snake.contains(&index) {
+                    return Err(format!("The snake goes through {} twice.", name));
+                }
+                layout.snake.push(index);
+            }
+        }
+        keyword => return Err(format!("Unknown keyword {}.", keyword)),
+    }
+
+    Ok(())
+}
+
+fn find_district(layout: &Layout, name: &str) -> Result<usize, String> {
+    layout.districts.iter()
+        .position(|district| district.name == name)
+        .ok_or_else(|| format!("Unknown district {}.", name))
+}
+
+/// Checks the coordinate and returns it as a float literal.
+fn parse_coordinate(token: &str, limit: f32) -> Result<String, String> {
+    match token.parse::<f32>() {
+        Ok(value) if value.is_finite() && value.abs() <= limit => {
+            if token.contains('.') {
+                Ok(token.to_string())
+            } else {
+                Ok(format!("{}.0", token))
+            }
+        }
+        _ => Err(format!("Invalid coordinate {}.", token)),
+    }
+}
+
+fn aliases(aliases: &[String]) -> String {
+    let aliases: Vec<String> = aliases.iter().map(|alias| format!("{:?}", alias)).collect();
+    aliases.join(", ")
+}
+
+fn generate(layout: &Layout) -> String {
+    let mut out = String::new();
+    let count = layout.districts.len();
+
+    writeln!(out, "/// Number of the LEDs of the board.").unwrap();
+    writeln!(out, "pub const LEDS_COUNT: usize = {};", count).unwrap();
+    writeln!(out).unwrap();
+
+    writeln!(out, "/// Layout of the board the crate is built for.").unwrap();
+    writeln!(out, "pub const LAYOUT: Layout = Layout {{").unwrap();
+    writeln!(out, "    name: {:?},", layout.name.as_deref().unwrap_or("Unnamed board")).unwrap();
+
+    writeln!(out, "    regions: &[").unwrap();
+    for region in &layout.regions {
+        writeln!(out, "        Region::new({:?}).with_aliases(&[{}]),", region.name, aliases(&region.aliases)).unwrap();
+    }
+    writeln!(out, "    ],").unwrap();
+
+    writeln!(out, "    districts: &[").unwrap();
+    for district in &layout.districts {
+        writeln!(
+            out,
+            "        District::new({:?}, {}, {}, {}).with_aliases(&[{}]),",
+            district.name, district.region, district.latitude, district.longitude, aliases(&district.aliases)
+        ).unwrap();
+    }
+    writeln!(out, "    ],").unwrap();
+
+    writeln!(out, "    borders: &[").unwrap();
+    for (a, b) in &layout.borders {
+        writeln!(out, "        ({}, {}),", a, b).unwrap();
+    }
+    writeln!(out, "    ],").unwrap();
+
+    let snake: Vec<String> = if layout.snake.is_empty() {
+        (0..count).map(|index| index.to_string()).collect()
+    } else {
+        layout.snake.iter().map(|index| index.to_string()).collect()
+    };
+    writeln!(out, "    snake: &[{}],", snake.join(", ")).unwrap();
+    writeln!(out, "}};").unwrap();
+
+    out
+}
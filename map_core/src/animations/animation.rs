@@ -4,7 +4,6 @@
 
 // each step will show current led states on the board
 
-use libm::{ceilf, powf};
 use crate::animations::animation_step::AnimationStep;
 use crate::map::Map;
 
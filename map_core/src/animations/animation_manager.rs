@@ -1,9 +1,9 @@
 use embedded_hal::timer::CountDown;
 use fugit::MicrosDurationU64;
-use nb::Error::{Other, WouldBlock};
+use nb::Error::WouldBlock;
 use crate::animations::animation::{Animation, AnimationError};
 use crate::animations::animation_storage::AnimationStorage;
-use crate::map::Map;
+use crate::map::{Map, ANIMATION_LAYER};
 
 pub struct AnimationManager<Timer> {
     timer: Timer,
@@ -45,20 +45,18 @@ impl<Timer: CountDown<Time=MicrosDurationU64>> AnimationManager<Timer> {
                 return Err(WouldBlock);
             };
 
-            if !step_result.is_err() {
-                animation.apply(map)?;
+            if step_result.is_ok() {
+                let mut layer = map.layer_map(ANIMATION_LAYER).ok().unwrap();
+                animation.apply(&mut layer)?;
             }
         }
 
         let step = match step_result {
             Ok(step) => step,
-            Err(err) if err == AnimationError::LastStep => {
+            Err(AnimationError::LastStep) => {
                 self.storage.remove_animation();
                 return Ok(());
             }
-            Err(err) => {
-                return Err(Other(err));
-            }
         };
 
         self.timer.start(step.duration());
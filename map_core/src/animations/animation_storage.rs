@@ -3,6 +3,7 @@ use crate::animations::animation::{Animation, AnimationError};
 use crate::animations::animation_step::AnimationStep;
 use crate::map::Map;
 
+#[derive(Default)]
 pub struct AnimationStorage {
     animation: Option<Box<dyn Animation>>,
 }
@@ -22,11 +23,11 @@ impl AnimationStorage {
         }
     }
 
-    pub fn set_animation<T: Animation + 'static>(&mut self, animation: T) -> () {
+    pub fn set_animation<T: Animation + 'static>(&mut self, animation: T) {
         self.animation = Some(Box::new(animation));
     }
 
-    pub fn remove_animation(&mut self) -> () {
+    pub fn remove_animation(&mut self) {
         self.animation = None;
     }
 }
@@ -0,0 +1,132 @@
+use fugit::MicrosDurationU64;
+use libm::{ceilf, powf};
+use smart_leds::RGB8;
+use crate::animations::animation::{Animation, AnimationError};
+use crate::animations::animation_step::AnimationStep;
+use crate::map::Map;
+
+pub struct SnakeAnimation<const LEDS_COUNT: usize> {
+    step: usize,
+    finished: bool,
+    order: [usize; LEDS_COUNT],
+    previous_factor: f32,
+    color: RGB8,
+    step_duration: MicrosDurationU64,
+}
+
+impl<const LEDS_COUNT: usize> SnakeAnimation<LEDS_COUNT> {
+    pub fn new(order: [usize; LEDS_COUNT], previous_factor: f32, color: RGB8, step_duration: MicrosDurationU64) -> Self {
+        Self {
+            step: 0,
+            finished: false,
+            order,
+            previous_factor,
+            color,
+            step_duration
+        }
+    }
+
+    fn is_last_step(&self) -> bool {
+        self.finished
+    }
+}
+
+impl<const LEDS_COUNT: usize> Animation for SnakeAnimation<LEDS_COUNT> {
+    fn is_started(&self) -> bool {
+        self.step > 0
+    }
+
+    fn init(&mut self) -> Result<(), AnimationError> {
+        Ok(())
+    }
+
+    fn next(&mut self) -> Result<AnimationStep, AnimationError> {
+        let last = LEDS_COUNT + 100;
+        if self.step == last {
+            return Err(AnimationError::LastStep);
+        }
+
+        self.step += 1;
+        if self.step == last {
+            self.finished = true;
+        }
+
+        Ok(AnimationStep::new(self.step_duration))
+    }
+
+    fn apply(&mut self, map: &mut Map) -> Result<(), AnimationError> {
+        // only the visible part of the snake is drawn, the faded out LEDs show the colors below again
+        map.clear();
+
+        for (i, led_index) in self.order.iter().take(self.step.min(LEDS_COUNT)).enumerate() {
+            let mult_factor = self.step - i - 1;
+            let coeff = powf(self.previous_factor, mult_factor as f32);
+            let rgb = self.color;
+            if (rgb.r.max(rgb.g).max(rgb.b) as f32) * coeff < 0.5 {
+                continue;
+            }
+            let color = RGB8 {
+                r: ceilf(rgb.r as f32 * coeff) as u8,
+                g: ceilf(rgb.g as f32 * coeff) as u8,
+                b: ceilf(rgb.b as f32 * coeff) as u8,
+            };
+
+            map.set(*led_index, color).ok().unwrap();
+        }
+
+        if self.is_last_step() {
+            map.clear();
+        }
+
+        Ok(())
+    }
+}
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use fugit::ExtU64;
+    use smart_leds::RGB8;
+    use crate::animations::animation::Animation;
+    use crate::constants::LEDS_COUNT;
+    use crate::map::layout::LAYOUT;
+    use crate::map::{Map, ANIMATION_LAYER};
+    use super::SnakeAnimation;
+
+    const RED: RGB8 = RGB8 { r: 255, g: 0, b: 0 };
+    const BLUE: RGB8 = RGB8 { r: 0, g: 0, b: 255 };
+
+    #[test]
+    fn faded_out_tail_shows_the_colors_below() {
+        let mut data = vec![RED; LEDS_COUNT];
+        let mut map = Map::new(&LAYOUT, &mut data);
+        let order: [usize; LEDS_COUNT] = core::array::from_fn(|index| index);
+        let mut snake = SnakeAnimation::new(order, 0.5, BLUE, 100.millis());
+
+        for _ in 0..20 {
+            snake.next().ok().unwrap();
+            snake.apply(&mut map.layer_map(ANIMATION_LAYER).ok().unwrap()).ok().unwrap();
+        }
+
+        let frame: Vec<RGB8> = map.frame().collect();
+        // the head is the 20th LED, the ones left far behind have faded out
+        assert_eq!(frame[19], BLUE);
+        assert_eq!(frame[18], RGB8 { r: 0, g: 0, b: 128 });
+        assert_eq!(frame[0], RED);
+        assert!(frame[20..].iter().all(|rgb| *rgb == RED));
+    }
+
+    #[test]
+    fn finished_snake_leaves_the_colors_below() {
+        let mut data = vec![RED; LEDS_COUNT];
+        let mut map = Map::new(&LAYOUT, &mut data);
+        let order: [usize; LEDS_COUNT] = core::array::from_fn(|index| index);
+        let mut snake = SnakeAnimation::new(order, 0.9, BLUE, 100.millis());
+
+        while snake.next().is_ok() {
+            snake.apply(&mut map.layer_map(ANIMATION_LAYER).ok().unwrap()).ok().unwrap();
+        }
+
+        assert!(map.frame().all(|rgb| rgb == RED));
+    }
+}
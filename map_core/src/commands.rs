@@ -0,0 +1,93 @@
+pub mod hello_world_command;
+pub mod command_handler;
+pub mod command;
+pub mod command_argument;
+pub mod set_command;
+pub mod reset_command;
+pub mod all_command;
+pub mod command_data;
+pub mod snake_command;
+pub mod region_command;
+pub mod hops_command;
+pub mod layer_command;
+pub mod gamma_command;
+pub mod balance_command;
+pub mod brightness_command;
+pub mod power_command;
+pub mod value_command;
+pub mod colormap_command;
+pub mod scale_command;
+pub mod classes_command;
+pub mod category_command;
+pub mod legend_command;
+pub mod scene_command;
+pub mod config_command;
+pub mod startup_command;
+pub mod baud_command;
+pub mod undo_command;
+pub mod redo_command;
+pub mod locate_command;
+pub mod near_command;
+
+use alloc::boxed::Box;
+use crate::commands::all_command::AllCommand;
+use crate::commands::balance_command::BalanceCommand;
+use crate::commands::baud_command::BaudCommand;
+use crate::commands::brightness_command::BrightnessCommand;
+use crate::commands::category_command::CategoryCommand;
+use crate::commands::classes_command::ClassesCommand;
+use crate::commands::colormap_command::ColormapCommand;
+use crate::commands::command_handler::SpecificCommandHandler;
+use crate::commands::config_command::ConfigCommand;
+use crate::commands::gamma_command::GammaCommand;
+use crate::commands::hello_world_command::HelloWorldCommand;
+use crate::commands::hops_command::HopsCommand;
+use crate::commands::layer_command::LayerCommand;
+use crate::commands::legend_command::LegendCommand;
+use crate::commands::locate_command::LocateCommand;
+use crate::commands::near_command::NearCommand;
+use crate::commands::power_command::PowerCommand;
+use crate::commands::redo_command::RedoCommand;
+use crate::commands::region_command::RegionCommand;
+use crate::commands::reset_command::ResetCommand;
+use crate::commands::scale_command::ScaleCommand;
+use crate::commands::scene_command::SceneCommand;
+use crate::commands::set_command::SetCommand;
+use crate::commands::snake_command::SnakeCommand;
+use crate::commands::startup_command::StartupCommand;
+use crate::commands::undo_command::UndoCommand;
+use crate::commands::value_command::ValueCommand;
+
+pub const HANDLERS_COUNT: usize = 26;
+
+/// All the commands supported by the board, shared by the firmware and the simulator.
+pub fn handlers() -> [(&'static str, Box<dyn SpecificCommandHandler>); HANDLERS_COUNT] {
+    [
+        ("HELLO_WORLD", Box::new(HelloWorldCommand)),
+        ("SET", Box::new(SetCommand)),
+        ("RESET", Box::new(ResetCommand)),
+        ("ALL", Box::new(AllCommand)),
+        ("SNAKE", Box::new(SnakeCommand::default())),
+        ("REGION", Box::new(RegionCommand)),
+        ("HOPS", Box::new(HopsCommand)),
+        ("LAYER", Box::new(LayerCommand)),
+        ("GAMMA", Box::new(GammaCommand)),
+        ("BALANCE", Box::new(BalanceCommand)),
+        ("BRIGHTNESS", Box::new(BrightnessCommand)),
+        ("POWER", Box::new(PowerCommand)),
+        ("VALUE", Box::new(ValueCommand)),
+        ("COLORMAP", Box::new(ColormapCommand)),
+        ("SCALE", Box::new(ScaleCommand)),
+        ("CLASSES", Box::new(ClassesCommand)),
+        ("CATEGORY", Box::new(CategoryCommand)),
+        ("LEGEND", Box::new(LegendCommand)),
+        ("SCENE", Box::new(SceneCommand)),
+        ("CONFIG", Box::new(ConfigCommand)),
+        ("STARTUP", Box::new(StartupCommand)),
+        ("BAUD", Box::new(BaudCommand)),
+        ("UNDO", Box::new(UndoCommand)),
+        ("REDO", Box::new(RedoCommand)),
+        ("LOCATE", Box::new(LocateCommand)),
+        ("NEAR", Box::new(NearCommand))
+    ]
+}
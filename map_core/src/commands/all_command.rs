@@ -0,0 +1,40 @@
+use crate::commands::command_argument::CommandArgument;
+use crate::commands::command_handler::{CommandHandleError, SpecificCommandHandler};
+use crate::commands::command_handler::CommandHandleError::WrongArguments;
+use crate::commands::command_data::CommandData;
+
+#[derive(Default)]
+pub struct AllCommand;
+
+impl SpecificCommandHandler for AllCommand {
+    fn handle(&self, command: CommandData) -> Result<(), CommandHandleError> {
+        let (cmd, map, out) = command.deconstruct_map();
+
+        if cmd.parsed_arguments().len() < 2 {
+            writeln!(out, "Less than 2 args.\r").ok();
+            return Err(WrongArguments);
+        }
+
+        let color = match CommandArgument::parse_color(&cmd.parsed_arguments()[1..]) {
+            Some((color, used)) if used == cmd.parsed_arguments()[1..].len() => color,
+            Some(_) => {
+                writeln!(out, "Too many args after the color.\r").ok();
+                return Err(WrongArguments);
+            }
+            None => {
+                writeln!(out, "Could not parse the color.\r").ok();
+                return Err(WrongArguments);
+            }
+        };
+
+        for led in map.get_map_mut() {
+            *led = color;
+        }
+
+        Ok(())
+    }
+
+    fn help(&self) -> &'static str {
+        "<color> - light up all LEDs to the given color"
+    }
+}
\ No newline at end of file
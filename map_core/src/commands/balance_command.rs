@@ -0,0 +1,43 @@
+use smart_leds::RGB8;
+use crate::commands::command_handler::{CommandHandleError, SpecificCommandHandler};
+use crate::commands::command_handler::CommandHandleError::WrongArguments;
+use crate::commands::command_data::CommandData;
+
+#[derive(Default)]
+pub struct BalanceCommand;
+
+impl SpecificCommandHandler for BalanceCommand {
+    fn handle(&self, command: CommandData) -> Result<(), CommandHandleError> {
+        let (cmd, settings, out) = command.deconstruct_settings();
+
+        if cmd.parsed_arguments().len() < 2 {
+            let balance = settings.correction().balance();
+            writeln!(out, "White balance: {} {} {}\r", balance.r, balance.g, balance.b).ok();
+            return Ok(());
+        }
+
+        if cmd.parsed_arguments().len() < 4 {
+            writeln!(out, "Less than 4 args.\r").ok();
+            return Err(WrongArguments);
+        }
+
+        let mut channels = [0u8; 3];
+        for (channel, (argument, name)) in channels.iter_mut().zip(cmd.parsed_arguments()[1..4].iter().zip(["r", "g", "b"])) {
+            *channel = match argument.parse_u8() {
+                Ok(value) => value,
+                Err(err) => {
+                    writeln!(out, "Could not parse {}, {}.\r", name, err).ok();
+                    return Err(WrongArguments);
+                }
+            };
+        }
+
+        let [r, g, b] = channels;
+        settings.correction_mut().set_balance(RGB8 { r, g, b });
+        Ok(())
+    }
+
+    fn help(&self) -> &'static str {
+        "[<R> <G> <B>] - Set the white balance, each channel is scaled by the value / 255, shows the current one without arguments"
+    }
+}
@@ -0,0 +1,42 @@
+use crate::commands::command_handler::{CommandHandleError, SpecificCommandHandler};
+use crate::commands::command_handler::CommandHandleError::WrongArguments;
+use crate::commands::command_data::CommandData;
+use crate::config;
+
+#[derive(Default)]
+pub struct BaudCommand;
+
+impl SpecificCommandHandler for BaudCommand {
+    fn handle(&self, command: CommandData) -> Result<(), CommandHandleError> {
+        let (cmd, _, _, storage, out) = command.deconstruct_config();
+
+        if cmd.parsed_arguments().len() < 2 {
+            writeln!(out, "Baud rate after restart: {}\r", config::baud_rate(storage)).ok();
+            return Ok(());
+        }
+
+        let baud_rate = match cmd.parsed_arguments()[1].parse_u32() {
+            Ok(baud_rate) if config::BAUD_RATES.contains(&baud_rate) => baud_rate,
+            Ok(_) => {
+                writeln!(out, "Unsupported baud rate, use one of {:?}.\r", config::BAUD_RATES).ok();
+                return Err(WrongArguments);
+            }
+            Err(err) => {
+                writeln!(out, "Could not parse the baud rate, {}.\r", err).ok();
+                return Err(WrongArguments);
+            }
+        };
+
+        if let Err(err) = config::set_baud_rate(storage, baud_rate) {
+            writeln!(out, "Could not store the baud rate ({:?}).\r", err).ok();
+            return Err(WrongArguments);
+        }
+
+        writeln!(out, "The baud rate will be used after restart.\r").ok();
+        Ok(())
+    }
+
+    fn help(&self) -> &'static str {
+        "[rate] - Set the baud rate of the console used after restart, shows it without arguments"
+    }
+}
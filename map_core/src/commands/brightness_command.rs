@@ -0,0 +1,32 @@
+use crate::commands::command_handler::{CommandHandleError, SpecificCommandHandler};
+use crate::commands::command_handler::CommandHandleError::WrongArguments;
+use crate::commands::command_data::CommandData;
+
+#[derive(Default)]
+pub struct BrightnessCommand;
+
+impl SpecificCommandHandler for BrightnessCommand {
+    fn handle(&self, command: CommandData) -> Result<(), CommandHandleError> {
+        let (cmd, settings, out) = command.deconstruct_settings();
+
+        if cmd.parsed_arguments().len() < 2 {
+            writeln!(out, "Brightness: {}\r", settings.brightness()).ok();
+            return Ok(());
+        }
+
+        let brightness = match cmd.parsed_arguments()[1].parse_level() {
+            Ok(brightness) => brightness,
+            Err(err) => {
+                writeln!(out, "Could not parse brightness, {}.\r", err).ok();
+                return Err(WrongArguments);
+            }
+        };
+
+        settings.set_brightness(brightness);
+        Ok(())
+    }
+
+    fn help(&self) -> &'static str {
+        "[brightness] - Set the brightness of all LEDs (255 max or a percentage), shows the current one without arguments"
+    }
+}
@@ -0,0 +1,51 @@
+use crate::commands::command_argument::CommandArgument;
+use crate::commands::command_handler::{CommandHandleError, SpecificCommandHandler};
+use crate::commands::command_handler::CommandHandleError::WrongArguments;
+use crate::commands::command_data::CommandData;
+
+#[derive(Default)]
+pub struct CategoryCommand;
+
+impl SpecificCommandHandler for CategoryCommand {
+    fn handle(&self, command: CommandData) -> Result<(), CommandHandleError> {
+        let (cmd, map, out) = command.deconstruct_map();
+        let arguments = &cmd.parsed_arguments()[1..];
+
+        if arguments.len() == 1 && arguments[0].compare("CLEAR") {
+            let cleared = map.clear_categories();
+            writeln!(out, "Cleared the categories of {} districts.\r", cleared).ok();
+            return Ok(());
+        }
+
+        if arguments.len() < 2 {
+            writeln!(out, "Less than 2 args after the command.\r").ok();
+            return Err(WrongArguments);
+        }
+
+        let label = arguments[0];
+        if label.try_to_float().is_some() || label.compare("CLEAR") {
+            writeln!(out, "The label cannot be a number or CLEAR.\r").ok();
+            return Err(WrongArguments);
+        }
+
+        let color = match CommandArgument::parse_color(&arguments[1..]) {
+            Some((color, used)) if used == arguments[1..].len() => color,
+            Some(_) => {
+                writeln!(out, "Too many args after the color.\r").ok();
+                return Err(WrongArguments);
+            }
+            None => {
+                writeln!(out, "Could not parse the color.\r").ok();
+                return Err(WrongArguments);
+            }
+        };
+
+        map.choropleth_mut().set_category(label.chars(), color);
+        map.render_choropleth();
+        Ok(())
+    }
+
+    fn help(&self) -> &'static str {
+        "<label> <color> | CLEAR - Define a category to be assigned to the districts by the VALUE command"
+    }
+}
@@ -0,0 +1,59 @@
+use alloc::vec::Vec;
+use crate::commands::command_argument::CommandArgument;
+use crate::commands::command_handler::{CommandHandleError, SpecificCommandHandler};
+use crate::commands::command_handler::CommandHandleError::WrongArguments;
+use crate::commands::command_data::CommandData;
+use crate::map::choropleth::Classes;
+
+#[derive(Default)]
+pub struct ClassesCommand;
+
+impl ClassesCommand {
+    /// Colors separated by thresholds, `<color> [<threshold> <color>...]`.
+    fn parse_classes(arguments: &[CommandArgument]) -> Option<Classes> {
+        let mut thresholds = Vec::new();
+        let mut colors = Vec::new();
+
+        let (color, used) = CommandArgument::parse_color(arguments)?;
+        colors.push(color);
+        let mut arguments = &arguments[used..];
+
+        while !arguments.is_empty() {
+            thresholds.push(arguments[0].try_to_float()?);
+            let (color, used) = CommandArgument::parse_color(&arguments[1..])?;
+            colors.push(color);
+            arguments = &arguments[1 + used..];
+        }
+
+        Classes::new(thresholds, colors)
+    }
+}
+
+impl SpecificCommandHandler for ClassesCommand {
+    fn handle(&self, command: CommandData) -> Result<(), CommandHandleError> {
+        let (cmd, map, out) = command.deconstruct_map();
+        let arguments = &cmd.parsed_arguments()[1..];
+
+        if arguments.len() == 1 && arguments[0].compare("OFF") {
+            map.choropleth_mut().set_classes(None);
+            map.render_choropleth();
+            return Ok(());
+        }
+
+        let classes = match Self::parse_classes(arguments) {
+            Some(classes) => classes,
+            None => {
+                writeln!(out, "Could not parse the classes, expected colors separated by ascending thresholds.\r").ok();
+                return Err(WrongArguments);
+            }
+        };
+
+        map.choropleth_mut().set_classes(Some(classes));
+        map.render_choropleth();
+        Ok(())
+    }
+
+    fn help(&self) -> &'static str {
+        "<color> [<threshold> <color>...] | OFF - Show the values of the VALUE command by classes instead of the colormap"
+    }
+}
@@ -0,0 +1,75 @@
+use alloc::vec::Vec;
+use crate::commands::command_argument::CommandArgument;
+use crate::commands::command_handler::{CommandHandleError, SpecificCommandHandler};
+use crate::commands::command_handler::CommandHandleError::WrongArguments;
+use crate::commands::command_data::CommandData;
+use crate::map::colormap::{Colormap, Stop};
+
+#[derive(Default)]
+pub struct ColormapCommand;
+
+impl ColormapCommand {
+    /// Stops given as `<position> <color>`, positions have to be ascending.
+    fn parse_stops(mut arguments: &[CommandArgument]) -> Option<Vec<Stop>> {
+        let mut stops: Vec<Stop> = Vec::new();
+        while !arguments.is_empty() {
+            let position = arguments[0].try_to_float().filter(|position| (0.0..=1.0).contains(position))?;
+            if stops.last().is_some_and(|(last, _)| *last > position) {
+                return None;
+            }
+
+            let (color, used) = CommandArgument::parse_color(&arguments[1..])?;
+            stops.push((position, color));
+            arguments = &arguments[1 + used..];
+        }
+
+        Some(stops).filter(|stops| stops.len() >= 2)
+    }
+
+    fn parse_colormap(arguments: &[CommandArgument]) -> Option<Colormap> {
+        let name = arguments.first()?;
+
+        if name.compare("VIRIDIS") {
+            Some(Colormap::Viridis)
+        } else if name.compare("MAGMA") {
+            Some(Colormap::Magma)
+        } else if name.compare("REDBLUE") {
+            Some(Colormap::RedBlue)
+        } else if name.compare("HUE") {
+            CommandArgument::parse_color(&arguments[1..])
+                .filter(|(_, used)| 1 + used == arguments.len())
+                .map(|(color, _)| Colormap::Hue(color))
+        } else if name.compare("CUSTOM") {
+            Self::parse_stops(&arguments[1..]).map(Colormap::Custom)
+        } else {
+            None
+        }
+    }
+}
+
+impl SpecificCommandHandler for ColormapCommand {
+    fn handle(&self, command: CommandData) -> Result<(), CommandHandleError> {
+        let (cmd, map, out) = command.deconstruct_map();
+
+        if cmd.parsed_arguments().len() < 2 {
+            writeln!(out, "Colormap: {}\r", map.choropleth().colormap().name()).ok();
+            return Ok(());
+        }
+
+        let colormap = match Self::parse_colormap(&cmd.parsed_arguments()[1..]) {
+            Some(colormap) => colormap,
+            None => {
+                writeln!(out, "Could not parse the colormap.\r").ok();
+                return Err(WrongArguments);
+            }
+        };
+
+        map.choropleth_mut().set_colormap(colormap);
+        map.render_choropleth();
+        Ok(())
+    }
+
+    fn help(&self) -> &'static str {
+        "<VIRIDIS|MAGMA|REDBLUE|HUE <color>|CUSTOM <position> <color>...> - Set the colormap of the VALUE command"
+    }
+}
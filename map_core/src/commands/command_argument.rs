@@ -0,0 +1,536 @@
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter, Write};
+use fugit::{ExtU64, MicrosDurationU64};
+use libm::roundf;
+use smart_leds::RGB8;
+use crate::map::color::{kelvin, Hsl, Hsv, MAX_KELVIN, MIN_KELVIN, NAMED_COLORS};
+use crate::map::Map;
+
+/// Longest duration accepted, a day in microseconds.
+pub const MAX_DURATION_MICROS: u64 = 24 * 3_600_000_000;
+
+/// Why an argument could not be parsed as a number.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    Empty,
+    /// Not a number at all.
+    Invalid,
+    /// Too many digits to fit.
+    Overflow,
+    /// A number, but not in the allowed range.
+    OutOfRange,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "missing"),
+            ParseError::Invalid => write!(f, "not a number"),
+            ParseError::Overflow => write!(f, "too large"),
+            ParseError::OutOfRange => write!(f, "out of range"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct CommandArgument<'d>
+{
+    pub data: &'d [char],
+}
+
+impl<'d> CommandArgument<'d>
+{
+    pub fn new(data: &'d [char]) -> Self
+    {
+        Self {
+            data
+        }
+    }
+
+    pub fn chars(&self) -> &'d [char]
+    {
+        self.data
+    }
+
+    /// Parses an unsigned integer, decimal or hexadecimal with `0x` prefix.
+    pub fn parse_u32(&self) -> Result<u32, ParseError>
+    {
+        if self.data.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let (digits, radix) = match self.data {
+            ['0', 'x' | 'X', digits @ ..] => (digits, 16),
+            digits => (digits, 10),
+        };
+
+        if digits.is_empty() {
+            return Err(ParseError::Invalid);
+        }
+
+        let mut result = 0u32;
+        for c in digits {
+            let num = c.to_digit(radix).ok_or(ParseError::Invalid)?;
+            result = result.checked_mul(radix)
+                .and_then(|result| result.checked_add(num))
+                .ok_or(ParseError::Overflow)?;
+        }
+
+        Ok(result)
+    }
+
+    pub fn parse_u8(&self) -> Result<u8, ParseError>
+    {
+        self.parse_u32()?.try_into().map_err(|_| ParseError::OutOfRange)
+    }
+
+    /// Parses an integer with an optional `-` sign.
+    pub fn parse_i32(&self) -> Result<i32, ParseError>
+    {
+        match self.data.split_first() {
+            Some((&'-', rest)) => {
+                let magnitude = CommandArgument::new(rest).parse_u32()?;
+                0i32.checked_sub_unsigned(magnitude).ok_or(ParseError::OutOfRange)
+            }
+            _ => self.parse_u32()?.try_into().map_err(|_| ParseError::OutOfRange)
+        }
+    }
+
+    /// Parses a decimal number such as `2.2` or `-0.5`.
+    pub fn parse_f32(&self) -> Result<f32, ParseError>
+    {
+        if let Some((&'-', rest)) = self.data.split_first() {
+            if rest.first() == Some(&'-') {
+                return Err(ParseError::Invalid);
+            }
+            return CommandArgument::new(rest).parse_f32().map(|value| -value);
+        }
+
+        let mut parts = self.data.splitn(2, |c| *c == '.');
+        let integer = parts.next().unwrap_or(&[]);
+        if integer.iter().any(|c| !c.is_ascii_digit()) {
+            return Err(ParseError::Invalid);
+        }
+        let mut result = CommandArgument::new(integer).parse_u32()? as f32;
+
+        if let Some(fraction) = parts.next() {
+            if fraction.is_empty() {
+                return Err(ParseError::Invalid);
+            }
+
+            let mut scale = 0.1;
+            for c in fraction {
+                result += c.to_digit(10).ok_or(ParseError::Invalid)? as f32 * scale;
+                scale /= 10.0;
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Parses a percentage such as `50%` as a fraction, 0.5.
+    pub fn parse_percent(&self) -> Result<f32, ParseError>
+    {
+        match self.data.split_last() {
+            Some((&'%', number)) => CommandArgument::new(number).parse_f32().map(|percent| percent / 100.0),
+            Some(_) => Err(ParseError::Invalid),
+            None => Err(ParseError::Empty)
+        }
+    }
+
+    /// Parses a level of 0 - 255 given either directly or as a percentage such as `50%`.
+    /// Decimal fractions are only accepted as percentages, `0.5` is not a level.
+    pub fn parse_level(&self) -> Result<u8, ParseError>
+    {
+        if self.data.last() != Some(&'%') {
+            return self.parse_u8();
+        }
+
+        let fraction = self.parse_percent()?;
+        if !(0.0..=1.0).contains(&fraction) {
+            return Err(ParseError::OutOfRange);
+        }
+
+        Ok(roundf(fraction * 255.0) as u8)
+    }
+
+    /// Parses a fraction of 0.0 - 1.0 given either as a decimal number such as `0.85`
+    /// or as a percentage such as `85%`.
+    pub fn parse_fraction(&self) -> Result<f32, ParseError>
+    {
+        let fraction = if self.data.last() == Some(&'%') {
+            self.parse_percent()?
+        } else {
+            self.parse_f32()?
+        };
+
+        if !(0.0..=1.0).contains(&fraction) {
+            return Err(ParseError::OutOfRange);
+        }
+
+        Ok(fraction)
+    }
+
+    /// Parses an angle in degrees such as `49.2` or `-16.6` within -`limit` - `limit`.
+    pub fn parse_degrees(&self, limit: f32) -> Result<f32, ParseError>
+    {
+        let degrees = self.parse_f32()?;
+        if !(-limit..=limit).contains(&degrees) {
+            return Err(ParseError::OutOfRange);
+        }
+
+        Ok(degrees)
+    }
+
+    /// Parses a duration made of numbers followed by units `us`, `ms`, `s`, `m` or `h`,
+    /// such as `250ms`, `2s` or `1m30s`. A bare number is in milliseconds.
+    /// Durations longer than `MAX_DURATION_MICROS` are out of range.
+    pub fn parse_duration(&self) -> Result<MicrosDurationU64, ParseError>
+    {
+        let total = self.parse_micros()?;
+        if total > MAX_DURATION_MICROS {
+            return Err(ParseError::OutOfRange);
+        }
+
+        Ok(total.micros())
+    }
+
+    fn parse_micros(&self) -> Result<u64, ParseError>
+    {
+        if self.data.iter().all(|c| c.is_ascii_digit()) {
+            let millis = self.parse_u32()? as u64;
+            return millis.checked_mul(1_000).ok_or(ParseError::Overflow);
+        }
+
+        let mut total = 0u64;
+        let mut rest = self.data;
+
+        while !rest.is_empty() {
+            let digits = rest.iter().take_while(|c| c.is_ascii_digit()).count();
+            let number = CommandArgument::new(&rest[..digits]).parse_u32()? as u64;
+            rest = &rest[digits..];
+
+            let letters = rest.iter().take_while(|c| c.is_ascii_alphabetic()).count();
+            let unit = CommandArgument::new(&rest[..letters]);
+            rest = &rest[letters..];
+
+            let micros = if unit.compare("US") {
+                1
+            } else if unit.compare("MS") {
+                1_000
+            } else if unit.compare("S") {
+                1_000_000
+            } else if unit.compare("M") {
+                60_000_000
+            } else if unit.compare("H") {
+                3_600_000_000
+            } else {
+                return Err(ParseError::Invalid);
+            };
+
+            total = number.checked_mul(micros)
+                .and_then(|part| total.checked_add(part))
+                .ok_or(ParseError::Overflow)?;
+        }
+
+        Ok(total)
+    }
+
+    pub fn try_to_integer(&self) -> Option<u32>
+    {
+        self.parse_u32().ok()
+    }
+
+    pub fn try_to_float(&self) -> Option<f32>
+    {
+        self.parse_f32().ok()
+    }
+
+    /// Parses six hexadecimal digits, `RRGGBB`.
+    fn try_to_hex_color(digits: &[char]) -> Option<RGB8>
+    {
+        if digits.len() != 6 {
+            return None;
+        }
+
+        let mut value = 0u32;
+        for c in digits {
+            value = value * 16 + c.to_digit(16)?;
+        }
+
+        let [_, r, g, b] = value.to_be_bytes();
+        Some(RGB8 { r, g, b })
+    }
+
+    /// Parses a color given as a single token, either hexadecimal `#FF8800` or `0xFF8800`,
+    /// a name such as `ORANGE` (see `NAMED_COLORS`), a color temperature such as `2700K`
+    /// or a functional form `HSV(<hue>,<saturation>,<value>)`, `HSL(<hue>,<saturation>,<lightness>)`
+    /// with the hue in degrees and the rest in percent, or `RGB(<R>,<G>,<B>)`.
+    pub fn try_to_color(&self) -> Option<RGB8>
+    {
+        if let Some(digits) = self.data.strip_prefix(&['#']) {
+            return Self::try_to_hex_color(digits);
+        }
+
+        if let Some(digits) = self.data.strip_prefix(&['0', 'x']).or_else(|| self.data.strip_prefix(&['0', 'X'])) {
+            return Self::try_to_hex_color(digits);
+        }
+
+        if let Some((_, color)) = NAMED_COLORS.iter().find(|(name, _)| self.compare(name)) {
+            return Some(*color);
+        }
+
+        let (last, rest) = self.data.split_last()?;
+
+        if last.eq_ignore_ascii_case(&'K') {
+            let temperature = CommandArgument::new(rest).try_to_integer()
+                .filter(|temperature| (MIN_KELVIN..=MAX_KELVIN).contains(temperature))?;
+            return Some(kelvin(temperature));
+        }
+
+        if *last != ')' {
+            return None;
+        }
+
+        let open = rest.iter().position(|c| *c == '(')?;
+        let name = CommandArgument::new(&rest[..open]);
+        let values = rest[open + 1..]
+            .split(|c| *c == ',')
+            .map(|value| CommandArgument::new(value).try_to_float())
+            .collect::<Option<Vec<f32>>>()?;
+
+        let &[first, second, third] = values.as_slice() else {
+            return None;
+        };
+
+        let percent = |value: f32| Some(value / 100.0).filter(|value| (0.0..=1.0).contains(value));
+
+        if name.compare("HSV") {
+            Some(Hsv::new(first, percent(second)?, percent(third)?).to_rgb())
+        } else if name.compare("HSL") {
+            Some(Hsl::new(first, percent(second)?, percent(third)?).to_rgb())
+        } else if name.compare("RGB") {
+            let channel = |value: f32| Some(value as u8).filter(|_| (0.0..=255.0).contains(&value));
+            Some(RGB8 { r: channel(first)?, g: channel(second)?, b: channel(third)? })
+        } else {
+            None
+        }
+    }
+
+    /// Parses a color given either as a single token, see `try_to_color`, or as `<R> <G> <B>`
+    /// levels, see `parse_level`.
+    /// Returns the color and the number of the arguments it takes.
+    pub fn parse_color(arguments: &[CommandArgument]) -> Option<(RGB8, usize)>
+    {
+        if let Some(color) = arguments.first()?.try_to_color() {
+            return Some((color, 1));
+        }
+
+        let r = arguments.first()?.parse_level().ok()?;
+        let g = arguments.get(1)?.parse_level().ok()?;
+        let b = arguments.get(2)?.parse_level().ok()?;
+
+        Some((RGB8 { r, g, b }, 3))
+    }
+
+    /// Resolves the LED given either by its index or by the name of its district.
+    pub fn try_to_led(&self, map: &Map) -> Option<usize>
+    {
+        if let Some(id) = self.try_to_integer() {
+            Some(id as usize).filter(|id| *id < map.districts().len())
+        } else {
+            map.get_index_by_name(self.chars()).ok()
+        }
+    }
+
+    /// Resolves the LEDs given by a selector, see `Map::select`.
+    pub fn try_to_leds(&self, map: &Map) -> Option<Vec<usize>>
+    {
+        map.select(self.chars()).ok()
+    }
+
+    /// Writes districts with names similar to the argument,
+    /// to be used when `try_to_led` fails.
+    pub fn write_led_suggestions(&self, map: &Map, out: &mut dyn Write)
+    {
+        let suggestions = map.suggest(self.chars(), 5);
+        if suggestions.is_empty() {
+            return;
+        }
+
+        write!(out, "Did you mean {}", map.districts()[suggestions[0]].name).ok();
+        for index in &suggestions[1..] {
+            write!(out, ", {}", map.districts()[*index].name).ok();
+        }
+        writeln!(out, "?\r").ok();
+    }
+
+    pub fn compare(&self, to: &str) -> bool {
+        if self.data.len() != to.len() {
+            return false;
+        }
+
+        let to = to.as_bytes();
+        for (i, c) in self.data.iter().enumerate() {
+            let compare_against = to[i];
+
+            if compare_against != c.to_ascii_uppercase() as u8 {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use smart_leds::RGB8;
+    use super::{CommandArgument, ParseError, MAX_DURATION_MICROS};
+
+    fn parse<T>(argument: &str, parser: impl Fn(&CommandArgument) -> T) -> T {
+        let chars: Vec<char> = argument.chars().collect();
+        parser(&CommandArgument::new(&chars))
+    }
+
+    #[test]
+    fn parses_unsigned_integers() {
+        assert_eq!(parse("42", |argument| argument.parse_u32()), Ok(42));
+        assert_eq!(parse("0x1F", |argument| argument.parse_u32()), Ok(31));
+        assert_eq!(parse("4294967295", |argument| argument.parse_u32()), Ok(u32::MAX));
+        assert_eq!(parse("4294967296", |argument| argument.parse_u32()), Err(ParseError::Overflow));
+        assert_eq!(parse("", |argument| argument.parse_u32()), Err(ParseError::Empty));
+        assert_eq!(parse("0x", |argument| argument.parse_u32()), Err(ParseError::Invalid));
+        assert_eq!(parse("12a", |argument| argument.parse_u32()), Err(ParseError::Invalid));
+        assert_eq!(parse("-1", |argument| argument.parse_u32()), Err(ParseError::Invalid));
+        assert_eq!(parse("256", |argument| argument.parse_u8()), Err(ParseError::OutOfRange));
+    }
+
+    #[test]
+    fn parses_signed_integers() {
+        assert_eq!(parse("-42", |argument| argument.parse_i32()), Ok(-42));
+        assert_eq!(parse("-2147483648", |argument| argument.parse_i32()), Ok(i32::MIN));
+        assert_eq!(parse("2147483648", |argument| argument.parse_i32()), Err(ParseError::OutOfRange));
+        assert_eq!(parse("-2147483649", |argument| argument.parse_i32()), Err(ParseError::OutOfRange));
+        assert_eq!(parse("--1", |argument| argument.parse_i32()), Err(ParseError::Invalid));
+        assert_eq!(parse("-", |argument| argument.parse_i32()), Err(ParseError::Empty));
+    }
+
+    #[test]
+    fn parses_decimal_numbers() {
+        assert_eq!(parse("2.25", |argument| argument.parse_f32()), Ok(2.25));
+        assert_eq!(parse("-0.5", |argument| argument.parse_f32()), Ok(-0.5));
+        assert_eq!(parse("3", |argument| argument.parse_f32()), Ok(3.0));
+        assert_eq!(parse("1.", |argument| argument.parse_f32()), Err(ParseError::Invalid));
+        assert_eq!(parse("1.2.3", |argument| argument.parse_f32()), Err(ParseError::Invalid));
+        assert_eq!(parse("0x1.5", |argument| argument.parse_f32()), Err(ParseError::Invalid));
+        assert_eq!(parse("--1", |argument| argument.parse_f32()), Err(ParseError::Invalid));
+    }
+
+    #[test]
+    fn parses_percentages() {
+        assert_eq!(parse("50%", |argument| argument.parse_percent()), Ok(0.5));
+        assert_eq!(parse("12.5%", |argument| argument.parse_percent()), Ok(0.125));
+        assert_eq!(parse("50", |argument| argument.parse_percent()), Err(ParseError::Invalid));
+        assert_eq!(parse("%", |argument| argument.parse_percent()), Err(ParseError::Empty));
+        assert_eq!(parse("", |argument| argument.parse_percent()), Err(ParseError::Empty));
+    }
+
+    #[test]
+    fn parses_levels() {
+        assert_eq!(parse("128", |argument| argument.parse_level()), Ok(128));
+        assert_eq!(parse("0x80", |argument| argument.parse_level()), Ok(128));
+        assert_eq!(parse("100%", |argument| argument.parse_level()), Ok(255));
+        assert_eq!(parse("50%", |argument| argument.parse_level()), Ok(128));
+        assert_eq!(parse("0%", |argument| argument.parse_level()), Ok(0));
+        assert_eq!(parse("256", |argument| argument.parse_level()), Err(ParseError::OutOfRange));
+        assert_eq!(parse("101%", |argument| argument.parse_level()), Err(ParseError::OutOfRange));
+        assert_eq!(parse("-1%", |argument| argument.parse_level()), Err(ParseError::OutOfRange));
+        assert_eq!(parse("0.5", |argument| argument.parse_level()), Err(ParseError::Invalid));
+    }
+
+    #[test]
+    fn parses_fractions() {
+        assert_eq!(parse("0.85", |argument| argument.parse_fraction()), Ok(0.85));
+        assert_eq!(parse("85%", |argument| argument.parse_fraction()), Ok(0.85));
+        assert_eq!(parse("1", |argument| argument.parse_fraction()), Ok(1.0));
+        assert_eq!(parse("0", |argument| argument.parse_fraction()), Ok(0.0));
+        assert_eq!(parse("1.5", |argument| argument.parse_fraction()), Err(ParseError::OutOfRange));
+        assert_eq!(parse("-0.1", |argument| argument.parse_fraction()), Err(ParseError::OutOfRange));
+        assert_eq!(parse("120%", |argument| argument.parse_fraction()), Err(ParseError::OutOfRange));
+        assert_eq!(parse("x", |argument| argument.parse_fraction()), Err(ParseError::Invalid));
+        assert_eq!(parse("", |argument| argument.parse_fraction()), Err(ParseError::Empty));
+    }
+
+    #[test]
+    fn parses_degrees() {
+        assert_eq!(parse("49.2", |argument| argument.parse_degrees(90.0)), Ok(49.2));
+        assert_eq!(parse("-90", |argument| argument.parse_degrees(90.0)), Ok(-90.0));
+        assert_eq!(parse("180", |argument| argument.parse_degrees(180.0)), Ok(180.0));
+        assert_eq!(parse("90.5", |argument| argument.parse_degrees(90.0)), Err(ParseError::OutOfRange));
+        assert_eq!(parse("-999", |argument| argument.parse_degrees(180.0)), Err(ParseError::OutOfRange));
+        assert_eq!(parse("N49", |argument| argument.parse_degrees(90.0)), Err(ParseError::Invalid));
+    }
+
+    fn micros(argument: &str) -> Result<u64, ParseError> {
+        parse(argument, |argument| argument.parse_duration()).map(|duration| duration.ticks())
+    }
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(micros("250"), Ok(250_000));
+        assert_eq!(micros("250ms"), Ok(250_000));
+        assert_eq!(micros("15us"), Ok(15));
+        assert_eq!(micros("2s"), Ok(2_000_000));
+        assert_eq!(micros("1m30s"), Ok(90_000_000));
+        assert_eq!(micros("1H"), Ok(3_600_000_000));
+        assert_eq!(micros("86400000"), Ok(MAX_DURATION_MICROS));
+        assert_eq!(micros("24h"), Ok(MAX_DURATION_MICROS));
+        assert_eq!(micros("23h59m59s999ms1000us"), Ok(MAX_DURATION_MICROS));
+        assert_eq!(micros("24h1us"), Err(ParseError::OutOfRange));
+        assert_eq!(micros("4294967295"), Err(ParseError::OutOfRange));
+        assert_eq!(micros(""), Err(ParseError::Empty));
+        assert_eq!(micros("s"), Err(ParseError::Empty));
+        assert_eq!(micros("2d"), Err(ParseError::Invalid));
+        assert_eq!(micros("1.5s"), Err(ParseError::Invalid));
+        assert_eq!(micros("4294967296"), Err(ParseError::Overflow));
+        assert_eq!(micros("4294967295h"), Err(ParseError::OutOfRange));
+        assert_eq!(micros("4294967295h4294967295h"), Err(ParseError::Overflow));
+    }
+
+    fn color(argument: &str) -> Option<RGB8> {
+        parse(argument, |argument| argument.try_to_color())
+    }
+
+    #[test]
+    fn parses_colors() {
+        let orange = Some(RGB8 { r: 0xFF, g: 0x88, b: 0x00 });
+        assert_eq!(color("#FF8800"), orange);
+        assert_eq!(color("0xff8800"), orange);
+        assert_eq!(color("FF8800"), None);
+        assert_eq!(color("#FF880"), None);
+        assert_eq!(color("#FF88GG"), None);
+        assert_eq!(color("red"), Some(RGB8 { r: 255, g: 0, b: 0 }));
+        assert_eq!(color("HSV(120,100,100)"), Some(RGB8 { r: 0, g: 255, b: 0 }));
+        assert_eq!(color("hsl(240,100,50)"), Some(RGB8 { r: 0, g: 0, b: 255 }));
+        assert_eq!(color("RGB(1,2,3)"), Some(RGB8 { r: 1, g: 2, b: 3 }));
+        assert_eq!(color("RGB(1,2,256)"), None);
+        assert_eq!(color("HSV(0,101,100)"), None);
+        assert_eq!(color("HSV(0,100)"), None);
+        assert_eq!(color("XYZ(1,2,3)"), None);
+        assert!(color("2700K").is_some());
+        assert_eq!(color("999K"), None);
+        assert_eq!(color("40001K"), None);
+    }
+
+    #[test]
+    fn parses_colors_spanning_arguments() {
+        let chars: Vec<Vec<char>> = ["255", "50%", "0", "#000000"].iter().map(|argument| argument.chars().collect()).collect();
+        let arguments: Vec<CommandArgument> = chars.iter().map(|chars| CommandArgument::new(chars)).collect();
+
+        assert_eq!(CommandArgument::parse_color(&arguments), Some((RGB8 { r: 255, g: 128, b: 0 }, 3)));
+        assert_eq!(CommandArgument::parse_color(&arguments[3..]), Some((RGB8 { r: 0, g: 0, b: 0 }, 1)));
+        assert_eq!(CommandArgument::parse_color(&arguments[1..3]), None);
+        assert_eq!(CommandArgument::parse_color(&[]), None);
+    }
+}
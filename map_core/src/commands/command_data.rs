@@ -0,0 +1,91 @@
+use core::fmt::Write;
+use crate::animations::animation_storage::AnimationStorage;
+use crate::commands::command::Command;
+use crate::map::Map;
+use crate::output::OutputSettings;
+use crate::scenes::Scenes;
+use crate::storage::Storage;
+
+pub struct CommandData<'d, 'a> {
+    command: &'d Command<'d>,
+    map: &'d mut Map<'a>,
+    animation_storage: &'d mut AnimationStorage,
+    settings: &'d mut OutputSettings,
+    scenes: &'d mut Scenes,
+    storage: &'d mut dyn Storage,
+    output: &'d mut dyn Write,
+}
+
+impl<'d, 'a> CommandData<'d, 'a> {
+    pub fn new(command: &'d Command<'d>, map: &'d mut Map<'a>, animation_manager: &'d mut AnimationStorage, settings: &'d mut OutputSettings, scenes: &'d mut Scenes, storage: &'d mut dyn Storage, output: &'d mut dyn Write) -> Self {
+        CommandData {
+            command,
+            map,
+            animation_storage: animation_manager,
+            settings,
+            scenes,
+            storage,
+            output,
+        }
+    }
+
+    pub fn animation_storage(self) -> &'d mut AnimationStorage {
+        self.animation_storage
+    }
+
+    pub fn command(self) -> &'d Command<'d> {
+        self.command
+    }
+
+    pub fn map(self) -> &'d mut Map<'a> {
+        self.map
+    }
+
+    pub fn settings(self) -> &'d mut OutputSettings {
+        self.settings
+    }
+
+    pub fn scenes(self) -> &'d mut Scenes {
+        self.scenes
+    }
+
+    pub fn storage(self) -> &'d mut dyn Storage {
+        self.storage
+    }
+
+    pub fn output(self) -> &'d mut dyn Write {
+        self.output
+    }
+
+    pub fn deconstruct_output(self) -> (&'d Command<'d>, &'d mut dyn Write) {
+        (self.command, self.output)
+    }
+
+    pub fn deconstruct_map(self) -> (&'d Command<'d>, &'d mut Map<'a>, &'d mut dyn Write) {
+        (self.command, self.map, self.output)
+    }
+
+    pub fn deconstruct_animation(self) -> (&'d Command<'d>, &'d mut AnimationStorage, &'d mut dyn Write) {
+        (self.command, self.animation_storage, self.output)
+    }
+
+    pub fn deconstruct_settings(self) -> (&'d Command<'d>, &'d mut OutputSettings, &'d mut dyn Write) {
+        (self.command, self.settings, self.output)
+    }
+
+    pub fn deconstruct_map_settings(self) -> (&'d Command<'d>, &'d mut Map<'a>, &'d mut OutputSettings, &'d mut dyn Write) {
+        (self.command, self.map, self.settings, self.output)
+    }
+
+    pub fn deconstruct_scenes(self) -> (&'d Command<'d>, &'d mut Map<'a>, &'d mut Scenes, &'d mut dyn Storage, &'d mut dyn Write) {
+        (self.command, self.map, self.scenes, self.storage, self.output)
+    }
+
+    pub fn deconstruct_config(self) -> (&'d Command<'d>, &'d mut OutputSettings, &'d mut Scenes, &'d mut dyn Storage, &'d mut dyn Write) {
+        (self.command, self.settings, self.scenes, self.storage, self.output)
+    }
+
+    pub fn deconstruct(self) -> (&'d Command<'d>, &'d mut Map<'a>, &'d mut AnimationStorage, &'d mut dyn Write) {
+        (self.command, self.map, self.animation_storage, self.output)
+    }
+}
@@ -1,16 +1,24 @@
 use alloc::boxed::Box;
+use core::fmt::Write as FmtWrite;
 use embedded_hal::serial::{Read, Write};
-use esp_println::println;
 use nb::block;
 use nb::Error::{Other, WouldBlock};
 use crate::animations::animation_storage::AnimationStorage;
-use crate::command_handler::{CommandHandleError::{CommandNotRead, NotFound}, CommandReadError::{BufferOverflowed, CommandLoadedAlready, UnexpectedEndOfLine}};
+use crate::commands::command_handler::{CommandHandleError::{CommandNotRead, NotFound}, CommandReadError::{BufferOverflowed, CommandLoadedAlready, UnexpectedEndOfLine}};
 use crate::commands::{command::Command, command_argument::CommandArgument, command_data::CommandData};
 use crate::map::Map;
+use crate::output::OutputSettings;
+use crate::scenes::Scenes;
+use crate::storage::Storage;
 
 pub trait SpecificCommandHandler {
     fn handle(&self, command: CommandData) -> Result<(), CommandHandleError>;
     fn help(&self) -> &'static str;
+
+    /// Whether the changes of the map made by the command can be undone.
+    fn records_history(&self) -> bool {
+        true
+    }
 }
 
 pub struct CommandHandler<'d, const BUFFER_SIZE: usize, const HANDLERS_COUNT: usize> {
@@ -18,7 +26,9 @@ pub struct CommandHandler<'d, const BUFFER_SIZE: usize, const HANDLERS_COUNT: us
     command_loaded: bool,
     buffer: [char; BUFFER_SIZE],
     handlers: [(&'d str, Box<dyn SpecificCommandHandler>); HANDLERS_COUNT],
-    handling_special: u8
+    handling_special: u8,
+    utf8: [u8; 4],
+    utf8_length: usize,
 }
 
 #[derive(Debug, Eq, PartialEq)]
@@ -41,17 +51,50 @@ impl<'d, const BUFFER_SIZE: usize, const HANDLERS_COUNT: usize> CommandHandler<'
         Self {
             command_loaded: false,
             handling_special: 0,
+            utf8: [0; 4],
+            utf8_length: 0,
             buffer_position: 0,
             buffer,
             handlers,
         }
     }
 
-    pub fn reset(&mut self) -> ()
+    pub fn reset(&mut self)
     {
         self.buffer_position = 0;
         self.command_loaded = false;
         self.handling_special = 0;
+        self.utf8_length = 0;
+    }
+
+    /// Collects the bytes of multibyte UTF-8 characters,
+    /// returns the character once all of its bytes are read.
+    fn decode_utf8(&mut self, data: u8) -> Option<char>
+    {
+        if self.utf8_length == 0 && data.is_ascii() {
+            return Some(data as char);
+        }
+
+        self.utf8[self.utf8_length] = data;
+        self.utf8_length += 1;
+
+        let expected_length = match self.utf8[0] {
+            0xC0..=0xDF => 2,
+            0xE0..=0xEF => 3,
+            0xF0..=0xF7 => 4,
+            _ => 1
+        };
+
+        if self.utf8_length < expected_length {
+            return None;
+        }
+
+        let decoded = core::str::from_utf8(&self.utf8[..self.utf8_length])
+            .ok()
+            .and_then(|decoded| decoded.chars().next())
+            .unwrap_or(char::REPLACEMENT_CHARACTER);
+        self.utf8_length = 0;
+        Some(decoded)
     }
 
     fn handle_special<Serial>(&mut self, serial: &mut Serial, data: u8) -> bool
@@ -93,10 +136,10 @@ impl<'d, const BUFFER_SIZE: usize, const HANDLERS_COUNT: usize> CommandHandler<'
             return Err(Other(BufferOverflowed));
         }
 
-        match read {
+        let data = match read {
             Ok(data) => {
                 if self.handle_special(serial, data) {
-                    self.buffer[self.buffer_position] = data as char
+                    data
                 } else { // special character handled
                     return Err(WouldBlock);
                 }
@@ -104,10 +147,15 @@ impl<'d, const BUFFER_SIZE: usize, const HANDLERS_COUNT: usize> CommandHandler<'
             Err(_) => return Err(WouldBlock)
         };
 
-        let read = self.buffer[self.buffer_position];
-        self.buffer_position += 1;
+        block!(serial.write(data)).ok().unwrap();
+
+        let read = match self.decode_utf8(data) {
+            Some(read) => read,
+            None => return Err(WouldBlock) // rest of the character not read yet
+        };
 
-        block!(serial.write(read as u8)).ok().unwrap();
+        self.buffer[self.buffer_position] = read;
+        self.buffer_position += 1;
 
         if read != '\r' {
             return Err(WouldBlock);
@@ -155,17 +203,26 @@ impl<'d, const BUFFER_SIZE: usize, const HANDLERS_COUNT: usize> CommandHandler<'
         Command::new(buffer, &args[0..length])
     }
 
-    fn handle_help(&self) -> Result<(), CommandHandleError>
+    fn handle_help(&self, map: &Map, output: &mut dyn FmtWrite) -> Result<(), CommandHandleError>
     {
-        println!("Available commands:\r");
+        writeln!(output, "Available commands:\r").ok();
         for (cmd, handler) in &self.handlers {
-            println!("  {0} {1}\r", cmd, handler.help());
+            writeln!(output, "  {0} {1}\r", cmd, handler.help()).ok();
+        }
+
+        writeln!(output, "Levels (brightness, opacity, R G B) are 0 - 255 or a percentage such as 50% or 12.5%,\r").ok();
+        writeln!(output, "  decimal fractions such as 0.5 are not levels. The SNAKE coeff is 0.0 - 1.0 or a percentage.\r").ok();
+        writeln!(output, "Names and aliases (plate codes such as BR) take precedence over the beginnings of names.\r").ok();
+
+        writeln!(output, "Available regions:\r").ok();
+        for region in map.regions() {
+            writeln!(output, "  {0}\r", region.name).ok();
         }
 
         Ok(())
     }
 
-    pub fn handle_command(&mut self, map: &mut Map, animation_storage: &mut AnimationStorage) -> Result<(), CommandHandleError>
+    pub fn handle_command(&mut self, map: &mut Map, animation_storage: &mut AnimationStorage, settings: &mut OutputSettings, scenes: &mut Scenes, storage: &mut dyn Storage, output: &mut dyn FmtWrite) -> Result<(), CommandHandleError>
     {
         if !self.command_loaded {
             return Err(CommandNotRead);
@@ -176,7 +233,7 @@ impl<'d, const BUFFER_SIZE: usize, const HANDLERS_COUNT: usize> CommandHandler<'
 
         let command = self.parse_command(buffer, &mut args);
 
-        if command.parsed_arguments().len() == 0 {
+        if command.parsed_arguments().is_empty() {
             self.reset();
             return Err(NotFound);
         }
@@ -184,7 +241,7 @@ impl<'d, const BUFFER_SIZE: usize, const HANDLERS_COUNT: usize> CommandHandler<'
         let first_argument = command.parsed_arguments()[0];
 
         if first_argument.compare("HELP") {
-            let help_handled = self.handle_help();
+            let help_handled = self.handle_help(map, output);
             self.reset();
             return help_handled;
         }
@@ -194,8 +251,12 @@ impl<'d, const BUFFER_SIZE: usize, const HANDLERS_COUNT: usize> CommandHandler<'
                 continue;
             }
 
-            let command_data = CommandData::new(&command, map, animation_storage);
+            let before = handler.records_history().then(|| map.snapshot());
+            let command_data = CommandData::new(&command, map, animation_storage, settings, scenes, storage, output);
             let handled = handler.handle(command_data);
+            if let Some(before) = before {
+                map.record(before);
+            }
             self.reset();
             return handled;
         }
@@ -0,0 +1,42 @@
+use crate::commands::command_handler::{CommandHandleError, SpecificCommandHandler};
+use crate::commands::command_handler::CommandHandleError::WrongArguments;
+use crate::commands::command_data::CommandData;
+use crate::config;
+
+#[derive(Default)]
+pub struct ConfigCommand;
+
+impl SpecificCommandHandler for ConfigCommand {
+    fn handle(&self, command: CommandData) -> Result<(), CommandHandleError> {
+        let (cmd, settings, _, storage, out) = command.deconstruct_config();
+
+        if cmd.parsed_arguments().len() < 2 {
+            writeln!(out, "Stored:\r").ok();
+            for key in storage.keys() {
+                writeln!(out, "  {}\r", key).ok();
+            }
+            return Ok(());
+        }
+
+        let action = &cmd.parsed_arguments()[1];
+        let result = if action.compare("SAVE") {
+            config::save_settings(storage, settings)
+        } else if action.compare("CLEAR") {
+            config::clear_settings(storage)
+        } else {
+            writeln!(out, "Unknown action, use SAVE or CLEAR.\r").ok();
+            return Err(WrongArguments);
+        };
+
+        if let Err(err) = result {
+            writeln!(out, "Could not store the settings ({:?}).\r", err).ok();
+            return Err(WrongArguments);
+        }
+
+        Ok(())
+    }
+
+    fn help(&self) -> &'static str {
+        "[SAVE|CLEAR] - Store the brightness, gamma, white balance and power budget to be used after restart, lists the stored keys without arguments"
+    }
+}
@@ -0,0 +1,38 @@
+use crate::commands::command_handler::{CommandHandleError, SpecificCommandHandler};
+use crate::commands::command_handler::CommandHandleError::WrongArguments;
+use crate::commands::command_data::CommandData;
+
+const MAX_GAMMA: f32 = 5.0;
+
+#[derive(Default)]
+pub struct GammaCommand;
+
+impl SpecificCommandHandler for GammaCommand {
+    fn handle(&self, command: CommandData) -> Result<(), CommandHandleError> {
+        let (cmd, settings, out) = command.deconstruct_settings();
+
+        if cmd.parsed_arguments().len() < 2 {
+            writeln!(out, "Gamma: {:.2}\r", settings.correction().gamma()).ok();
+            return Ok(());
+        }
+
+        let gamma = match cmd.parsed_arguments()[1].parse_f32() {
+            Ok(gamma) if gamma > 0.0 && gamma <= MAX_GAMMA => gamma,
+            Ok(_) => {
+                writeln!(out, "Could not parse gamma, out of range, expected a number between 0 and {}.\r", MAX_GAMMA).ok();
+                return Err(WrongArguments);
+            }
+            Err(err) => {
+                writeln!(out, "Could not parse gamma, {}.\r", err).ok();
+                return Err(WrongArguments);
+            }
+        };
+
+        settings.correction_mut().set_gamma(gamma);
+        Ok(())
+    }
+
+    fn help(&self) -> &'static str {
+        "[gamma] - Set the gamma correction of the LEDs (1 turns it off), shows the current one without arguments"
+    }
+}
@@ -1,5 +1,4 @@
-use esp_println::{print, println};
-use crate::command_handler::{CommandHandleError, SpecificCommandHandler};
+use crate::commands::command_handler::{CommandHandleError, SpecificCommandHandler};
 use crate::commands::command_data::CommandData;
 
 #[derive(Default)]
@@ -9,13 +8,13 @@ impl SpecificCommandHandler for HelloWorldCommand
 {
     fn handle(&self, command: CommandData) -> Result<(), CommandHandleError>
     {
-        let command = command.command();
+        let (command, out) = command.deconstruct_output();
         let full = command.full();
-        print!("Hello world!");
+        write!(out, "Hello world!").ok();
         for c in &full[command.parsed_arguments()[0].data.len()..] {
-            print!("{}", c);
+            write!(out, "{}", c).ok();
         }
-        println!("\r");
+        writeln!(out, "\r").ok();
 
         Ok(())
     }
@@ -23,4 +22,4 @@ impl SpecificCommandHandler for HelloWorldCommand
     fn help(&self) -> &'static str {
         "<x> - prints Hello world! <x>"
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,56 @@
+use crate::commands::command_argument::CommandArgument;
+use crate::commands::command_handler::{CommandHandleError, SpecificCommandHandler};
+use crate::commands::command_handler::CommandHandleError::WrongArguments;
+use crate::commands::command_data::CommandData;
+
+#[derive(Default)]
+pub struct HopsCommand;
+
+impl SpecificCommandHandler for HopsCommand {
+    fn handle(&self, command: CommandData) -> Result<(), CommandHandleError> {
+        let (cmd, map, out) = command.deconstruct_map();
+
+        if cmd.parsed_arguments().len() < 4 {
+            writeln!(out, "Less than 4 args.\r").ok();
+            return Err(WrongArguments);
+        }
+
+        let led_id = cmd.parsed_arguments()[1].try_to_led(map);
+
+        if led_id.is_none() {
+            writeln!(out, "Could not parse led id.\r").ok();
+            cmd.parsed_arguments()[1].write_led_suggestions(map, out);
+            return Err(WrongArguments);
+        }
+
+        let hops = match cmd.parsed_arguments()[2].parse_u32() {
+            Ok(hops) => hops,
+            Err(err) => {
+                writeln!(out, "Could not parse hops, {}.\r", err).ok();
+                return Err(WrongArguments);
+            }
+        };
+
+        let color = match CommandArgument::parse_color(&cmd.parsed_arguments()[3..]) {
+            Some((color, used)) if used == cmd.parsed_arguments()[3..].len() => color,
+            Some(_) => {
+                writeln!(out, "Too many args after the color.\r").ok();
+                return Err(WrongArguments);
+            }
+            None => {
+                writeln!(out, "Could not parse the color.\r").ok();
+                return Err(WrongArguments);
+            }
+        };
+
+        for index in map.within_hops(led_id.unwrap(), hops as usize).ok().unwrap() {
+            map.set_rgb(index, Some(color.r), Some(color.g), Some(color.b)).ok().unwrap();
+        }
+
+        Ok(())
+    }
+
+    fn help(&self) -> &'static str {
+        "<id or name> <hops> <color> - Set the LEDs at most the given number of borders away from the specified LED"
+    }
+}
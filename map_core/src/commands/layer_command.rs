@@ -0,0 +1,81 @@
+use crate::commands::command_argument::CommandArgument;
+use crate::commands::command_handler::{CommandHandleError, SpecificCommandHandler};
+use crate::commands::command_handler::CommandHandleError::WrongArguments;
+use crate::commands::command_data::CommandData;
+use crate::map::layer::BlendMode;
+use crate::map::{ANIMATION_LAYER, OVERLAY_LAYER};
+
+#[derive(Default)]
+pub struct LayerCommand;
+
+impl LayerCommand {
+    fn parse_layer(argument: &CommandArgument) -> Option<usize> {
+        if argument.compare("ANIMATION") {
+            Some(ANIMATION_LAYER)
+        } else if argument.compare("OVERLAY") {
+            Some(OVERLAY_LAYER)
+        } else {
+            argument.try_to_integer().map(|index| index as usize)
+        }
+    }
+
+    fn parse_blend_mode(argument: &CommandArgument) -> Option<BlendMode> {
+        BlendMode::ALL.into_iter().find(|mode| argument.compare(mode.name()))
+    }
+}
+
+impl SpecificCommandHandler for LayerCommand {
+    fn handle(&self, command: CommandData) -> Result<(), CommandHandleError> {
+        let (cmd, map, out) = command.deconstruct_map();
+
+        if cmd.parsed_arguments().len() == 1 {
+            for (index, layer) in map.layers().iter().enumerate() {
+                writeln!(out, "  {0} {1} {2}\r", index, layer.blend_mode().name(), layer.opacity()).ok();
+            }
+            return Ok(());
+        }
+
+        if cmd.parsed_arguments().len() < 3 {
+            writeln!(out, "Less than 3 args.\r").ok();
+            return Err(WrongArguments);
+        }
+
+        let blend_mode = match Self::parse_blend_mode(&cmd.parsed_arguments()[2]) {
+            Some(blend_mode) => blend_mode,
+            None => {
+                writeln!(out, "Unknown blend mode, use REPLACE, ADD, MULTIPLY, MAX or ALPHA.\r").ok();
+                return Err(WrongArguments);
+            }
+        };
+
+        let opacity = match cmd.parsed_arguments().get(3) {
+            Some(argument) => match argument.parse_level() {
+                Ok(opacity) => Some(opacity),
+                Err(err) => {
+                    writeln!(out, "Could not parse opacity, {}.\r", err).ok();
+                    return Err(WrongArguments);
+                }
+            },
+            None => None
+        };
+
+        let layer = match Self::parse_layer(&cmd.parsed_arguments()[1]).and_then(|index| map.layer_mut(index).ok()) {
+            Some(layer) => layer,
+            None => {
+                writeln!(out, "Could not find the layer.\r").ok();
+                return Err(WrongArguments);
+            }
+        };
+
+        layer.set_blend_mode(blend_mode);
+        if let Some(opacity) = opacity {
+            layer.set_opacity(opacity);
+        }
+
+        Ok(())
+    }
+
+    fn help(&self) -> &'static str {
+        "[<ANIMATION|OVERLAY|id> <REPLACE|ADD|MULTIPLY|MAX|ALPHA> [opacity]] - Set how the layer is blended over the colors below, lists the layers without arguments"
+    }
+}
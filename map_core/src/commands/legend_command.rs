@@ -0,0 +1,72 @@
+use smart_leds::RGB8;
+use crate::commands::command_handler::{CommandHandleError, SpecificCommandHandler};
+use crate::commands::command_data::CommandData;
+use crate::map::choropleth::Value;
+
+#[derive(Default)]
+pub struct LegendCommand;
+
+/// Colors of the colormap shown by the legend.
+const COLORMAP_STEPS: usize = 5;
+
+fn write_color(out: &mut dyn core::fmt::Write, rgb: RGB8) {
+    write!(out, "{} {} {}", rgb.r, rgb.g, rgb.b).ok();
+}
+
+impl SpecificCommandHandler for LegendCommand {
+    fn handle(&self, command: CommandData) -> Result<(), CommandHandleError> {
+        let (_, map, out) = command.deconstruct_map();
+        let choropleth = map.choropleth();
+
+        match choropleth.classes() {
+            Some(classes) => {
+                writeln!(out, "Classes:\r").ok();
+                let thresholds = classes.thresholds();
+                for (class, color) in classes.colors().iter().enumerate() {
+                    write!(out, "  ").ok();
+                    write_color(out, *color);
+                    match (class.checked_sub(1).map(|below| thresholds[below]), thresholds.get(class)) {
+                        (None, Some(to)) => write!(out, ": < {}", to),
+                        (Some(from), Some(to)) => write!(out, ": {} - {}", from, to),
+                        (Some(from), None) => write!(out, ": >= {}", from),
+                        (None, None) => write!(out, ": all")
+                    }.ok();
+                    writeln!(out, "\r").ok();
+                }
+            }
+            None => {
+                writeln!(out, "Colormap {}:\r", choropleth.colormap().name()).ok();
+                if choropleth.range().is_none() {
+                    writeln!(out, "  no values\r").ok();
+                } else if choropleth.value_at(0.0).is_none() {
+                    writeln!(out, "  the logarithmic scale needs positive limits\r").ok();
+                } else {
+                    for step in 0..COLORMAP_STEPS {
+                        let position = step as f32 / (COLORMAP_STEPS - 1) as f32;
+                        write!(out, "  ").ok();
+                        write_color(out, choropleth.colormap().color(position));
+                        writeln!(out, ": {}\r", choropleth.value_at(position).unwrap_or(0.0)).ok();
+                    }
+                    if choropleth.is_logarithmic() {
+                        writeln!(out, "  (logarithmic scale)\r").ok();
+                    }
+                }
+            }
+        }
+
+        if !choropleth.categories().is_empty() {
+            writeln!(out, "Categories:\r").ok();
+            for (category, (label, color)) in choropleth.categories().iter().enumerate() {
+                write!(out, "  ").ok();
+                write_color(out, *color);
+                writeln!(out, ": {} ({} districts)\r", label, choropleth.count(Value::Category(category))).ok();
+            }
+        }
+
+        Ok(())
+    }
+
+    fn help(&self) -> &'static str {
+        "- Print the colors of the values shown by the VALUE command"
+    }
+}
@@ -0,0 +1,67 @@
+use crate::commands::command_argument::CommandArgument;
+use crate::commands::command_handler::{CommandHandleError, SpecificCommandHandler};
+use crate::commands::command_handler::CommandHandleError::WrongArguments;
+use crate::commands::command_data::CommandData;
+use crate::map::district::Coordinates;
+
+#[derive(Default)]
+pub struct LocateCommand;
+
+impl SpecificCommandHandler for LocateCommand {
+    fn handle(&self, command: CommandData) -> Result<(), CommandHandleError> {
+        let (cmd, map, out) = command.deconstruct_map();
+
+        if cmd.parsed_arguments().len() < 4 {
+            writeln!(out, "Less than 4 args.\r").ok();
+            return Err(WrongArguments);
+        }
+
+        let latitude = match cmd.parsed_arguments()[1].parse_degrees(90.0) {
+            Ok(latitude) => latitude,
+            Err(err) => {
+                writeln!(out, "Could not parse latitude (-90 - 90), {}.\r", err).ok();
+                return Err(WrongArguments);
+            }
+        };
+
+        let longitude = match cmd.parsed_arguments()[2].parse_degrees(180.0) {
+            Ok(longitude) => longitude,
+            Err(err) => {
+                writeln!(out, "Could not parse longitude (-180 - 180), {}.\r", err).ok();
+                return Err(WrongArguments);
+            }
+        };
+
+        let color = match CommandArgument::parse_color(&cmd.parsed_arguments()[3..]) {
+            Some((color, used)) if used == cmd.parsed_arguments()[3..].len() => color,
+            Some(_) => {
+                writeln!(out, "Too many args after the color.\r").ok();
+                return Err(WrongArguments);
+            }
+            None => {
+                writeln!(out, "Could not parse the color.\r").ok();
+                return Err(WrongArguments);
+            }
+        };
+
+        let coordinates = Coordinates::new(latitude, longitude);
+        let index = match map.nearest(coordinates) {
+            Ok(index) => index,
+            Err(_) => {
+                writeln!(out, "The map has no districts.\r").ok();
+                return Err(WrongArguments);
+            }
+        };
+
+        map.set_rgb(index, Some(color.r), Some(color.g), Some(color.b)).ok().unwrap();
+
+        let district = map.district(index).ok().unwrap();
+        writeln!(out, "{} ({}), {:.1} km from its centre.\r", district.name, index, district.coordinates.distance(coordinates)).ok();
+
+        Ok(())
+    }
+
+    fn help(&self) -> &'static str {
+        "<latitude> <longitude> <color> - Set the LED of the district nearest to the coordinates"
+    }
+}
@@ -0,0 +1,85 @@
+use smart_leds::RGB8;
+use crate::commands::command_argument::CommandArgument;
+use crate::commands::command_handler::{CommandHandleError, SpecificCommandHandler};
+use crate::commands::command_handler::CommandHandleError::WrongArguments;
+use crate::commands::command_data::CommandData;
+use crate::map::district::Coordinates;
+use crate::map::Map;
+
+#[derive(Default)]
+pub struct NearCommand;
+
+impl NearCommand {
+    /// Distance and color given as `<km> <color>`, nothing may follow them.
+    fn parse_radius(arguments: &[CommandArgument]) -> Option<(f32, RGB8)> {
+        let distance = arguments.first()?.try_to_float().filter(|distance| *distance >= 0.0)?;
+        let (color, used) = CommandArgument::parse_color(&arguments[1..])?;
+
+        Some((distance, color)).filter(|_| 1 + used == arguments.len())
+    }
+
+    /// The centre given as a district followed by the radius.
+    fn parse_district(arguments: &[CommandArgument], map: &Map) -> Option<(Coordinates, f32, RGB8)> {
+        let led_id = arguments.first()?.try_to_led(map)?;
+        let (distance, color) = Self::parse_radius(&arguments[1..])?;
+
+        Some((map.coordinates(led_id).ok()?, distance, color))
+    }
+}
+
+impl SpecificCommandHandler for NearCommand {
+    fn handle(&self, command: CommandData) -> Result<(), CommandHandleError> {
+        let (cmd, map, out) = command.deconstruct_map();
+        let args = cmd.parsed_arguments();
+
+        if args.len() < 4 {
+            writeln!(out, "Less than 4 args.\r").ok();
+            return Err(WrongArguments);
+        }
+
+        // the centre is either a district or latitude and longitude
+        let (center, distance, color) = match Self::parse_district(&args[1..], map) {
+            Some(parsed) => parsed,
+            None if args[1].try_to_led(map).is_none() && args[1].try_to_float().is_none() => {
+                writeln!(out, "Could not parse led id.\r").ok();
+                args[1].write_led_suggestions(map, out);
+                return Err(WrongArguments);
+            }
+            None => {
+                let latitude = match args[1].parse_degrees(90.0) {
+                    Ok(latitude) => latitude,
+                    Err(err) => {
+                        writeln!(out, "Could not parse latitude (-90 - 90), {}.\r", err).ok();
+                        return Err(WrongArguments);
+                    }
+                };
+
+                let longitude = match args[2].parse_degrees(180.0) {
+                    Ok(longitude) => longitude,
+                    Err(err) => {
+                        writeln!(out, "Could not parse longitude (-180 - 180), {}.\r", err).ok();
+                        return Err(WrongArguments);
+                    }
+                };
+
+                match Self::parse_radius(&args[3..]) {
+                    Some((distance, color)) => (Coordinates::new(latitude, longitude), distance, color),
+                    None => {
+                        writeln!(out, "Could not parse distance or color.\r").ok();
+                        return Err(WrongArguments);
+                    }
+                }
+            }
+        };
+
+        for index in map.within_distance(center, distance) {
+            map.set(index, color).ok().unwrap();
+        }
+
+        Ok(())
+    }
+
+    fn help(&self) -> &'static str {
+        "<id or name | latitude longitude> <km> <color> - Set the LEDs of the districts with their centre within the distance"
+    }
+}
@@ -0,0 +1,43 @@
+use crate::commands::command_argument::ParseError;
+use crate::commands::command_handler::{CommandHandleError, SpecificCommandHandler};
+use crate::commands::command_handler::CommandHandleError::WrongArguments;
+use crate::commands::command_data::CommandData;
+use crate::output::power_limiter::MAX_BUDGET;
+
+#[derive(Default)]
+pub struct PowerCommand;
+
+impl SpecificCommandHandler for PowerCommand {
+    fn handle(&self, command: CommandData) -> Result<(), CommandHandleError> {
+        let (cmd, map, settings, out) = command.deconstruct_map_settings();
+
+        if cmd.parsed_arguments().len() < 2 {
+            match settings.limiter().budget() {
+                Some(budget) => writeln!(out, "Budget: {} mA\r", budget),
+                None => writeln!(out, "Budget: OFF\r")
+            }.ok();
+            writeln!(out, "Estimated without the limit: {} mA\r", settings.estimate(map.frame())).ok();
+            return Ok(());
+        }
+
+        let argument = &cmd.parsed_arguments()[1];
+        let budget = if argument.compare("OFF") {
+            None
+        } else {
+            match argument.parse_u32().and_then(|budget| if (1..=MAX_BUDGET).contains(&budget) { Ok(budget) } else { Err(ParseError::OutOfRange) }) {
+                Ok(budget) => Some(budget),
+                Err(err) => {
+                    writeln!(out, "Could not parse the budget (1 - {} mA), {}.\r", MAX_BUDGET, err).ok();
+                    return Err(WrongArguments);
+                }
+            }
+        };
+
+        settings.limiter_mut().set_budget(budget);
+        Ok(())
+    }
+
+    fn help(&self) -> &'static str {
+        "[<mA>|OFF] - Set the current budget the LEDs are dimmed to fit in, shows the budget and the estimated current without arguments"
+    }
+}
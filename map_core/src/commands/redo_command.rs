@@ -0,0 +1,25 @@
+use crate::commands::command_handler::{CommandHandleError, SpecificCommandHandler};
+use crate::commands::command_data::CommandData;
+
+#[derive(Default)]
+pub struct RedoCommand;
+
+impl SpecificCommandHandler for RedoCommand {
+    fn handle(&self, command: CommandData) -> Result<(), CommandHandleError> {
+        let (_, map, out) = command.deconstruct_map();
+
+        if !map.redo() {
+            writeln!(out, "Nothing to redo.\r").ok();
+        }
+
+        Ok(())
+    }
+
+    fn help(&self) -> &'static str {
+        "- Apply the last change reverted by UNDO again"
+    }
+
+    fn records_history(&self) -> bool {
+        false
+    }
+}
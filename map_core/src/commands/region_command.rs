@@ -0,0 +1,55 @@
+use core::fmt::Write;
+use crate::commands::command_argument::CommandArgument;
+use crate::commands::command_handler::{CommandHandleError, SpecificCommandHandler};
+use crate::commands::command_handler::CommandHandleError::WrongArguments;
+use crate::commands::command_data::CommandData;
+use crate::map::Map;
+
+#[derive(Default)]
+pub struct RegionCommand;
+
+impl RegionCommand {
+    /// Sets the color of all districts in a region,
+    /// the arguments are `<name> <color>`.
+    pub fn set_region(map: &mut Map, out: &mut dyn Write, arguments: &[CommandArgument]) -> Result<(), CommandHandleError> {
+        if arguments.len() < 2 {
+            writeln!(out, "Less than 2 args after the command.\r").ok();
+            return Err(WrongArguments);
+        }
+
+        let region = match map.get_region_by_name(arguments[0].chars()) {
+            Ok(region) => region,
+            Err(_) => {
+                writeln!(out, "Could not find the region.\r").ok();
+                return Err(WrongArguments);
+            }
+        };
+
+        let color = match CommandArgument::parse_color(&arguments[1..]) {
+            Some((color, used)) if used == arguments[1..].len() => color,
+            Some(_) => {
+                writeln!(out, "Too many args after the color.\r").ok();
+                return Err(WrongArguments);
+            }
+            None => {
+                writeln!(out, "Could not parse the color.\r").ok();
+                return Err(WrongArguments);
+            }
+        };
+
+        map.set_region_rgb(region, Some(color.r), Some(color.g), Some(color.b)).ok().unwrap();
+
+        Ok(())
+    }
+}
+
+impl SpecificCommandHandler for RegionCommand {
+    fn handle(&self, command: CommandData) -> Result<(), CommandHandleError> {
+        let (cmd, map, out) = command.deconstruct_map();
+        Self::set_region(map, out, &cmd.parsed_arguments()[1..])
+    }
+
+    fn help(&self) -> &'static str {
+        "<region> <color> - Set all LEDs of the region (kraj) to the given color"
+    }
+}
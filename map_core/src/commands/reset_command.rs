@@ -6,8 +6,10 @@ pub struct ResetCommand;
 
 impl SpecificCommandHandler for ResetCommand {
     fn handle(&self, command: CommandData) -> Result<(), CommandHandleError> {
-        let (_, map, animation) = command.deconstruct();
+        let (_, map, animation, _) = command.deconstruct();
         map.clear();
+        map.clear_layers();
+        map.choropleth_mut().clear();
         animation.remove_animation();
         Ok(())
     }
@@ -0,0 +1,74 @@
+use crate::commands::command_argument::CommandArgument;
+use crate::commands::command_handler::{CommandHandleError, SpecificCommandHandler};
+use crate::commands::command_handler::CommandHandleError::WrongArguments;
+use crate::commands::command_data::CommandData;
+
+#[derive(Default)]
+pub struct ScaleCommand;
+
+impl ScaleCommand {
+    /// `AUTO` is parsed as `Some(None)`, the limit is taken from the values then.
+    fn parse_limit(argument: &CommandArgument) -> Option<Option<f32>> {
+        if argument.compare("AUTO") {
+            Some(None)
+        } else {
+            argument.try_to_float().map(Some)
+        }
+    }
+}
+
+impl SpecificCommandHandler for ScaleCommand {
+    fn handle(&self, command: CommandData) -> Result<(), CommandHandleError> {
+        let (cmd, map, out) = command.deconstruct_map();
+        let mut arguments = &cmd.parsed_arguments()[1..];
+
+        if arguments.is_empty() {
+            let choropleth = map.choropleth();
+            match choropleth.range() {
+                Some((min, max)) => writeln!(out, "Range: {} - {}\r", min, max),
+                None => writeln!(out, "Range: no values\r")
+            }.ok();
+            writeln!(out, "Scale: {}\r", if choropleth.is_logarithmic() { "LOG" } else { "LINEAR" }).ok();
+            return Ok(());
+        }
+
+        let logarithmic = match arguments.last() {
+            Some(last) if last.compare("LOG") => Some(true),
+            Some(last) if last.compare("LINEAR") => Some(false),
+            _ => None
+        };
+        if logarithmic.is_some() {
+            arguments = &arguments[..arguments.len() - 1];
+        }
+
+        let limits = match arguments.len() {
+            0 => None,
+            2 => match (Self::parse_limit(&arguments[0]), Self::parse_limit(&arguments[1])) {
+                (Some(min), Some(max)) => Some((min, max)),
+                _ => {
+                    writeln!(out, "Could not parse min or max.\r").ok();
+                    return Err(WrongArguments);
+                }
+            },
+            _ => {
+                writeln!(out, "Expected min and max.\r").ok();
+                return Err(WrongArguments);
+            }
+        };
+
+        let choropleth = map.choropleth_mut();
+        if let Some((min, max)) = limits {
+            choropleth.set_limits(min, max);
+        }
+        if let Some(logarithmic) = logarithmic {
+            choropleth.set_logarithmic(logarithmic);
+        }
+
+        map.render_choropleth();
+        Ok(())
+    }
+
+    fn help(&self) -> &'static str {
+        "[<min|AUTO> <max|AUTO>] [LOG|LINEAR] - Set the range and the scale of the VALUE command, shows them without arguments"
+    }
+}
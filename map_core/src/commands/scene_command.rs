@@ -0,0 +1,77 @@
+use crate::commands::command_handler::{CommandHandleError, SpecificCommandHandler};
+use crate::commands::command_handler::CommandHandleError::WrongArguments;
+use crate::commands::command_data::CommandData;
+use crate::config;
+use crate::scenes::{SceneError, MAX_SCENES};
+
+#[derive(Default)]
+pub struct SceneCommand;
+
+impl SpecificCommandHandler for SceneCommand {
+    fn handle(&self, command: CommandData) -> Result<(), CommandHandleError> {
+        let (cmd, map, scenes, storage, out) = command.deconstruct_scenes();
+        let arguments = cmd.parsed_arguments();
+
+        if arguments.len() == 2 && arguments[1].compare("LIST") {
+            if scenes.scenes().is_empty() {
+                writeln!(out, "No scenes saved.\r").ok();
+            }
+            for scene in scenes.scenes() {
+                writeln!(out, "  {}\r", scene.name()).ok();
+            }
+            return Ok(());
+        }
+
+        if arguments.len() < 3 {
+            writeln!(out, "Less than 3 args.\r").ok();
+            return Err(WrongArguments);
+        }
+
+        let name = arguments[2].chars();
+        let result = if arguments[1].compare("SAVE") {
+            scenes.save(name, map)
+        } else if arguments[1].compare("LOAD") {
+            let loaded = scenes.load(name, map);
+            if loaded.is_ok() {
+                map.choropleth_mut().clear();
+            }
+            loaded
+        } else if arguments[1].compare("DELETE") {
+            if let Some(index) = scenes.find(name) {
+                match config::delete_scene(storage, scenes.scenes()[index].name()) {
+                    Ok(true) => writeln!(out, "It was the startup scene, no scene is shown after restart now.\r"),
+                    Ok(false) => Ok(()),
+                    Err(_) => writeln!(out, "Could not delete the stored scene.\r")
+                }.ok();
+            }
+            scenes.delete(name)
+        } else {
+            writeln!(out, "Unknown action, use SAVE, LOAD, LIST or DELETE.\r").ok();
+            return Err(WrongArguments);
+        };
+
+        if result.is_ok() && arguments[1].compare("SAVE") {
+            let scene = &scenes.scenes()[scenes.find(name).unwrap()];
+            if let Err(err) = config::save_scene(storage, scene) {
+                writeln!(out, "Could not store the scene ({:?}), it is kept until restart.\r", err).ok();
+                return Err(WrongArguments);
+            }
+        }
+
+        match result {
+            Ok(()) => Ok(()),
+            Err(err) => {
+                match err {
+                    SceneError::NotFound => writeln!(out, "Could not find the scene.\r"),
+                    SceneError::Full => writeln!(out, "Cannot save more than {} scenes.\r", MAX_SCENES),
+                    SceneError::WrongSize => writeln!(out, "The scene was saved for another board.\r")
+                }.ok();
+                Err(WrongArguments)
+            }
+        }
+    }
+
+    fn help(&self) -> &'static str {
+        "<SAVE|LOAD|DELETE> <name> | LIST - Save the colors of the map as a scene and show them again later"
+    }
+}
@@ -0,0 +1,53 @@
+use crate::commands::command_argument::CommandArgument;
+use crate::commands::command_handler::{CommandHandleError, SpecificCommandHandler};
+use crate::commands::command_handler::CommandHandleError::WrongArguments;
+use crate::commands::command_data::CommandData;
+use crate::commands::region_command::RegionCommand;
+
+#[derive(Default)]
+pub struct SetCommand;
+
+impl SpecificCommandHandler for SetCommand {
+    fn handle(&self, command: CommandData) -> Result<(), CommandHandleError> {
+        let (cmd, map, out) = command.deconstruct_map();
+
+        if cmd.parsed_arguments().len() > 1 && cmd.parsed_arguments()[1].compare("KRAJ") {
+            return RegionCommand::set_region(map, out, &cmd.parsed_arguments()[2..]);
+        }
+
+        if cmd.parsed_arguments().len() < 3 {
+            writeln!(out, "Less than 3 args.\r").ok();
+            return Err(WrongArguments);
+        }
+
+        let leds = cmd.parsed_arguments()[1].try_to_leds(map);
+
+        if leds.is_none() {
+            writeln!(out, "Could not parse led selector.\r").ok();
+            cmd.parsed_arguments()[1].write_led_suggestions(map, out);
+            return Err(WrongArguments);
+        }
+
+        let color = match CommandArgument::parse_color(&cmd.parsed_arguments()[2..]) {
+            Some((color, used)) if used == cmd.parsed_arguments()[2..].len() => color,
+            Some(_) => {
+                writeln!(out, "Too many args after the color.\r").ok();
+                return Err(WrongArguments);
+            }
+            None => {
+                writeln!(out, "Could not parse the color.\r").ok();
+                return Err(WrongArguments);
+            }
+        };
+
+        for index in leds.unwrap() {
+            map.set(index, color).ok().unwrap();
+        }
+
+        Ok(())
+    }
+
+    fn help(&self) -> &'static str {
+        "<selector | KRAJ region> <color> - Set the selected LEDs or all LEDs of the region to the given color"
+    }
+}
\ No newline at end of file
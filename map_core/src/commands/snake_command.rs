@@ -0,0 +1,121 @@
+use core::cell::Cell;
+use crate::animations::snake_animation::SnakeAnimation;
+use crate::commands::command_argument::CommandArgument;
+use crate::commands::command_data::CommandData;
+use crate::commands::command_handler::{CommandHandleError, SpecificCommandHandler};
+use crate::commands::command_handler::CommandHandleError::WrongArguments;
+use crate::constants;
+use crate::map::Map;
+use crate::map::order::Order;
+
+#[derive(Default)]
+pub struct SnakeCommand {
+    /// Seed of the next random order given without one.
+    seed: Cell<u32>,
+}
+
+impl SnakeCommand {
+    /// Order given as `SNAKE | WEST | NORTH | SPIRAL | FROM <led> | BFS <led> | RANDOM [seed]`,
+    /// `seed` is used by RANDOM without its own seed.
+    pub fn parse_order(arguments: &[CommandArgument], map: &Map, seed: u32) -> Option<Order> {
+        let name = arguments.first()?;
+
+        if name.compare("SNAKE") {
+            Some(Order::Snake)
+        } else if name.compare("WEST") {
+            Some(Order::WestEast)
+        } else if name.compare("NORTH") {
+            Some(Order::NorthSouth)
+        } else if name.compare("SPIRAL") {
+            Some(Order::Spiral)
+        } else if name.compare("FROM") {
+            arguments.get(1)?.try_to_led(map).map(Order::Distance)
+        } else if name.compare("BFS") {
+            arguments.get(1)?.try_to_led(map).map(Order::Breadth)
+        } else if name.compare("RANDOM") {
+            match arguments.get(1) {
+                Some(seed) => seed.try_to_integer().map(Order::Random),
+                None => Some(Order::Random(seed))
+            }
+        } else {
+            None
+        }
+    }
+}
+
+impl SpecificCommandHandler for SnakeCommand {
+    fn handle(&self, command: CommandData) -> Result<(), CommandHandleError> {
+        let (cmd, map, animation, out) = command.deconstruct();
+
+        if cmd.parsed_arguments().len() < 4 {
+            writeln!(out, "Less than 4 args.\r").ok();
+            return Err(WrongArguments);
+        }
+
+        let coeff = match cmd.parsed_arguments()[1].parse_fraction() {
+            Ok(coeff) => coeff,
+            Err(err) => {
+                writeln!(out, "Could not parse coeff (0.0 - 1.0 or a percentage), {}.\r", err).ok();
+                return Err(WrongArguments);
+            }
+        };
+
+        let color = CommandArgument::parse_color(&cmd.parsed_arguments()[2..]);
+        let rest = &cmd.parsed_arguments()[2 + color.map(|(_, used)| used).unwrap_or(0)..];
+        let (color, _) = match color {
+            Some(color) => color,
+            None => {
+                writeln!(out, "Could not parse the color.\r").ok();
+                return Err(WrongArguments);
+            }
+        };
+
+        let duration = match rest.first().map(|duration| duration.parse_duration()) {
+            Some(Ok(duration)) => duration,
+            Some(Err(err)) => {
+                writeln!(out, "Could not parse duration, {}.\r", err).ok();
+                return Err(WrongArguments);
+            }
+            None => {
+                writeln!(out, "Missing duration.\r").ok();
+                return Err(WrongArguments);
+            }
+        };
+
+        let order = if rest.len() > 1 {
+            if !rest[1].compare("ORDER") {
+                writeln!(out, "Expected ORDER after the duration.\r").ok();
+                return Err(WrongArguments);
+            }
+
+            let seed = self.seed.get().wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            self.seed.set(seed);
+
+            match Self::parse_order(&rest[2..], map, seed) {
+                Some(order) => order,
+                None => {
+                    writeln!(out, "Could not parse order, use SNAKE, WEST, NORTH, SPIRAL, FROM <led>, BFS <led> or RANDOM [seed].\r").ok();
+                    return Err(WrongArguments);
+                }
+            }
+        } else {
+            Order::Snake
+        };
+
+        let order: [usize; constants::LEDS_COUNT] = map.order(order).ok().unwrap().try_into()
+            .expect("The order goes through every district of the layout.");
+
+        animation
+            .set_animation(SnakeAnimation::<{ constants::LEDS_COUNT }>::new(
+                order,
+                coeff,
+                color,
+                duration)
+            );
+        Ok(())
+    }
+
+    fn help(&self) -> &'static str {
+        "<coeff> <color> <duration> [ORDER <order>] - Let snake run with the given base color and coefficient of light off (0.0 - 1.0 such as 0.85, or 85%), the duration of a step is e.g. 250ms or 2s"
+    }
+}
\ No newline at end of file
@@ -0,0 +1,45 @@
+use crate::commands::command_handler::{CommandHandleError, SpecificCommandHandler};
+use crate::commands::command_handler::CommandHandleError::WrongArguments;
+use crate::commands::command_data::CommandData;
+use crate::config;
+
+#[derive(Default)]
+pub struct StartupCommand;
+
+impl SpecificCommandHandler for StartupCommand {
+    fn handle(&self, command: CommandData) -> Result<(), CommandHandleError> {
+        let (cmd, _, scenes, storage, out) = command.deconstruct_config();
+
+        if cmd.parsed_arguments().len() < 2 {
+            match config::startup_scene(storage) {
+                Some(name) => writeln!(out, "Startup scene: {}\r", name),
+                None => writeln!(out, "Startup scene: OFF\r")
+            }.ok();
+            return Ok(());
+        }
+
+        let argument = &cmd.parsed_arguments()[1];
+        let name = if argument.compare("OFF") {
+            None
+        } else {
+            match scenes.find(argument.chars()) {
+                Some(index) => Some(scenes.scenes()[index].name()),
+                None => {
+                    writeln!(out, "Could not find the scene.\r").ok();
+                    return Err(WrongArguments);
+                }
+            }
+        };
+
+        if let Err(err) = config::set_startup_scene(storage, name) {
+            writeln!(out, "Could not store the startup scene ({:?}).\r", err).ok();
+            return Err(WrongArguments);
+        }
+
+        Ok(())
+    }
+
+    fn help(&self) -> &'static str {
+        "[<scene>|OFF] - Set the scene shown after the board starts, shows the current one without arguments"
+    }
+}
@@ -0,0 +1,25 @@
+use crate::commands::command_handler::{CommandHandleError, SpecificCommandHandler};
+use crate::commands::command_data::CommandData;
+
+#[derive(Default)]
+pub struct UndoCommand;
+
+impl SpecificCommandHandler for UndoCommand {
+    fn handle(&self, command: CommandData) -> Result<(), CommandHandleError> {
+        let (_, map, out) = command.deconstruct_map();
+
+        if !map.undo() {
+            writeln!(out, "Nothing to undo.\r").ok();
+        }
+
+        Ok(())
+    }
+
+    fn help(&self) -> &'static str {
+        "- Revert the last change of the map"
+    }
+
+    fn records_history(&self) -> bool {
+        false
+    }
+}
@@ -0,0 +1,73 @@
+use crate::commands::command_argument::CommandArgument;
+use crate::commands::command_handler::{CommandHandleError, SpecificCommandHandler};
+use crate::commands::command_handler::CommandHandleError::WrongArguments;
+use crate::commands::command_data::CommandData;
+use crate::map::choropleth::{Choropleth, Value};
+
+#[derive(Default)]
+pub struct ValueCommand;
+
+impl ValueCommand {
+    /// A number or a label of a category, `Some(None)` for `CLEAR`.
+    fn parse_value(choropleth: &Choropleth, argument: &CommandArgument) -> Option<Option<Value>> {
+        if argument.compare("CLEAR") {
+            return Some(None);
+        }
+
+        match argument.try_to_float() {
+            Some(number) => Some(Some(Value::Number(number))),
+            None => choropleth.find_category(argument.chars()).map(|category| Some(Value::Category(category)))
+        }
+    }
+}
+
+impl SpecificCommandHandler for ValueCommand {
+    fn handle(&self, command: CommandData) -> Result<(), CommandHandleError> {
+        let (cmd, map, out) = command.deconstruct_map();
+        let arguments = &cmd.parsed_arguments()[1..];
+
+        if arguments.len() == 1 && arguments[0].compare("CLEAR") {
+            let cleared = map.clear_choropleth();
+            writeln!(out, "Cleared {} values.\r", cleared).ok();
+            return Ok(());
+        }
+
+        if arguments.is_empty() || arguments.len() % 2 != 0 {
+            writeln!(out, "Expected pairs of led id and value.\r").ok();
+            return Err(WrongArguments);
+        }
+
+        // all the pairs are checked first so that a wrong one does not leave the values half set
+        for pair in arguments.chunks(2) {
+            if pair[0].try_to_led(map).is_none() {
+                writeln!(out, "Could not parse led id.\r").ok();
+                pair[0].write_led_suggestions(map, out);
+                return Err(WrongArguments);
+            }
+
+            if Self::parse_value(map.choropleth(), &pair[1]).is_none() {
+                writeln!(out, "Could not parse value, expected a number or a category.\r").ok();
+                return Err(WrongArguments);
+            }
+        }
+
+        for pair in arguments.chunks(2) {
+            let led_id = pair[0].try_to_led(map).unwrap();
+            match Self::parse_value(map.choropleth(), &pair[1]).unwrap() {
+                Some(value) => {
+                    map.choropleth_mut().set_value(led_id, Some(value));
+                }
+                None => {
+                    map.clear_value(led_id);
+                }
+            }
+        }
+
+        map.render_choropleth();
+        Ok(())
+    }
+
+    fn help(&self) -> &'static str {
+        "<id or name> <value|category|CLEAR> [<id or name> <value|category|CLEAR>...] | CLEAR - Show the values on the map using the colormap or the classes"
+    }
+}
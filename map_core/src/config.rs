@@ -0,0 +1,137 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use smart_leds::RGB8;
+use crate::map::Map;
+use crate::output::OutputSettings;
+use crate::scenes::{Scene, Scenes};
+use crate::storage::{Storage, StorageError};
+
+pub const BRIGHTNESS: &str = "brightness";
+pub const GAMMA: &str = "gamma";
+pub const BALANCE: &str = "balance";
+/// Current budget in milliamps, 0 if the current is not limited.
+pub const POWER: &str = "power";
+pub const BAUD_RATE: &str = "baud";
+/// Name of the scene loaded at boot.
+pub const STARTUP: &str = "startup";
+/// Prefix of the keys of the scenes, followed by the name of the scene.
+pub const SCENE_PREFIX: &str = "scene/";
+
+/// Keys of the settings saved by `save_settings`.
+pub const SETTINGS: [&str; 4] = [BRIGHTNESS, GAMMA, BALANCE, POWER];
+
+pub const DEFAULT_BAUD_RATE: u32 = 115200;
+pub const BAUD_RATES: [u32; 8] = [9600, 19200, 38400, 57600, 115200, 230400, 460800, 921600];
+
+fn get_u32(storage: &dyn Storage, key: &str) -> Option<u32> {
+    storage.get(key)?.try_into().ok().map(u32::from_le_bytes)
+}
+
+pub fn save_settings(storage: &mut dyn Storage, settings: &OutputSettings) -> Result<(), StorageError> {
+    let balance = settings.correction().balance();
+
+    storage.set(BRIGHTNESS, &[settings.brightness()])?;
+    storage.set(GAMMA, &settings.correction().gamma().to_le_bytes())?;
+    storage.set(BALANCE, &[balance.r, balance.g, balance.b])?;
+    storage.set(POWER, &settings.limiter().budget().unwrap_or(0).to_le_bytes())
+}
+
+pub fn load_settings(storage: &dyn Storage, settings: &mut OutputSettings) {
+    if let Some(&[brightness]) = storage.get(BRIGHTNESS) {
+        settings.set_brightness(brightness);
+    }
+
+    if let Some(gamma) = get_u32(storage, GAMMA).map(f32::from_bits) {
+        if gamma > 0.0 && gamma.is_finite() {
+            settings.correction_mut().set_gamma(gamma);
+        }
+    }
+
+    if let Some(&[r, g, b]) = storage.get(BALANCE) {
+        settings.correction_mut().set_balance(RGB8 { r, g, b });
+    }
+
+    if let Some(budget) = get_u32(storage, POWER) {
+        settings.limiter_mut().set_budget(Some(budget).filter(|budget| *budget > 0));
+    }
+}
+
+pub fn clear_settings(storage: &mut dyn Storage) -> Result<(), StorageError> {
+    for key in SETTINGS {
+        storage.remove(key)?;
+    }
+    Ok(())
+}
+
+/// Baud rate of the console, applied at boot.
+pub fn baud_rate(storage: &dyn Storage) -> u32 {
+    get_u32(storage, BAUD_RATE)
+        .filter(|baud_rate| BAUD_RATES.contains(baud_rate))
+        .unwrap_or(DEFAULT_BAUD_RATE)
+}
+
+pub fn set_baud_rate(storage: &mut dyn Storage, baud_rate: u32) -> Result<(), StorageError> {
+    storage.set(BAUD_RATE, &baud_rate.to_le_bytes())
+}
+
+pub fn startup_scene(storage: &dyn Storage) -> Option<&str> {
+    core::str::from_utf8(storage.get(STARTUP)?).ok()
+}
+
+pub fn set_startup_scene(storage: &mut dyn Storage, name: Option<&str>) -> Result<(), StorageError> {
+    match name {
+        Some(name) => storage.set(STARTUP, name.as_bytes()),
+        None => storage.remove(STARTUP)
+    }
+}
+
+fn scene_key(name: &str) -> String {
+    let mut key = String::from(SCENE_PREFIX);
+    key.push_str(name);
+    key
+}
+
+pub fn save_scene(storage: &mut dyn Storage, scene: &Scene) -> Result<(), StorageError> {
+    let colors: Vec<u8> = scene.colors().iter().flat_map(|rgb| [rgb.r, rgb.g, rgb.b]).collect();
+    storage.set(&scene_key(scene.name()), &colors)
+}
+
+/// Removes the stored scene, and the startup scene too if it is this one.
+/// Returns whether the startup scene was removed.
+pub fn delete_scene(storage: &mut dyn Storage, name: &str) -> Result<bool, StorageError> {
+    storage.remove(&scene_key(name))?;
+
+    if startup_scene(storage) == Some(name) {
+        set_startup_scene(storage, None)?;
+        return Ok(true);
+    }
+
+    Ok(false)
+}
+
+pub fn load_scenes(storage: &dyn Storage, scenes: &mut Scenes) {
+    for key in storage.keys() {
+        let name = match key.strip_prefix(SCENE_PREFIX) {
+            Some(name) => name,
+            None => continue
+        };
+
+        let colors = storage.get(key).unwrap_or(&[])
+            .chunks_exact(3)
+            .map(|rgb| RGB8 { r: rgb[0], g: rgb[1], b: rgb[2] })
+            .collect();
+        scenes.insert(Scene::new(String::from(name), colors)).ok();
+    }
+}
+
+/// Loads the settings and the scenes and shows the startup scene,
+/// to be called once at boot.
+pub fn load(storage: &dyn Storage, map: &mut Map, settings: &mut OutputSettings, scenes: &mut Scenes) {
+    load_settings(storage, settings);
+    load_scenes(storage, scenes);
+
+    if let Some(name) = startup_scene(storage) {
+        let name: Vec<char> = name.chars().collect();
+        scenes.load(&name, map).ok();
+    }
+}
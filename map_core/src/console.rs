@@ -0,0 +1,83 @@
+use core::fmt;
+use core::fmt::Write as FmtWrite;
+use embedded_hal::serial::{Read, Write};
+use nb::block;
+use nb::Error::Other;
+use crate::animations::animation_storage::AnimationStorage;
+use crate::commands::command_handler::{CommandHandleError, CommandHandler, CommandReadError};
+use crate::map::Map;
+use crate::output::OutputSettings;
+use crate::scenes::Scenes;
+use crate::storage::Storage;
+
+/// Allows formatted output to be written to a serial port.
+pub struct SerialWriter<'a, Serial> {
+    serial: &'a mut Serial,
+}
+
+impl<'a, Serial: Write<u8>> SerialWriter<'a, Serial> {
+    pub fn new(serial: &'a mut Serial) -> Self {
+        Self {
+            serial
+        }
+    }
+}
+
+impl<'a, Serial: Write<u8>> FmtWrite for SerialWriter<'a, Serial> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for byte in s.bytes() {
+            block!(self.serial.write(byte)).map_err(|_| fmt::Error)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn print_new_command<Serial: Write<u8>>(serial: &mut Serial) {
+    write!(SerialWriter::new(serial), "\r\n> ").ok();
+}
+
+/// Reads a character from the serial and handles the command once it is
+/// terminated. Errors are reported back to the serial and a new prompt
+/// is printed after each handled command.
+pub fn poll<Serial, const BUFFER_SIZE: usize, const HANDLERS_COUNT: usize>(
+    handler: &mut CommandHandler<'_, BUFFER_SIZE, HANDLERS_COUNT>,
+    serial: &mut Serial,
+    map: &mut Map,
+    animation_storage: &mut AnimationStorage,
+    settings: &mut OutputSettings,
+    scenes: &mut Scenes,
+    storage: &mut dyn Storage,
+) where Serial: Read<u8> + Write<u8>
+{
+    let new_command = match handler.read_command(serial) {
+        Ok(()) => {
+            let mut output = SerialWriter::new(serial);
+            writeln!(output, "\r").ok();
+            let result = handler.handle_command(map, animation_storage, settings, scenes, storage, &mut output);
+
+            if let Err(err) = result {
+                match err {
+                    CommandHandleError::NotFound => writeln!(output, "Command not found.\r"),
+                    CommandHandleError::WrongArguments => writeln!(output, "Wrong arguments.\r"),
+                    CommandHandleError::CommandNotRead => writeln!(output, "FATAL: Command is not prepared.\r")
+                }.ok();
+            }
+            true
+        },
+        Err(Other(error)) => {
+            let mut output = SerialWriter::new(serial);
+            match error {
+                CommandReadError::BufferOverflowed => writeln!(output, "Command is too long.\r").ok(),
+                CommandReadError::UnexpectedEndOfLine => None,
+                CommandReadError::CommandLoadedAlready => writeln!(output, "FATAL: Previous command not processed correctly.\r").ok()
+            };
+            true
+        }
+        Err(_) => false
+    };
+
+    if new_command {
+        print_new_command(serial);
+    }
+}
@@ -0,0 +1,2 @@
+pub const LEDS_COUNT: usize = crate::map::layout::LEDS_COUNT;
+pub const COMMAND_BUFFER: usize = 200;
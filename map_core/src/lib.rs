@@ -0,0 +1,14 @@
+#![no_std]
+
+extern crate alloc;
+
+pub mod map;
+pub mod commands;
+pub mod animations;
+pub mod console;
+pub mod constants;
+pub mod strip;
+pub mod output;
+pub mod scenes;
+pub mod storage;
+pub mod config;
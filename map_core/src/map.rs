@@ -0,0 +1,656 @@
+pub mod district;
+pub mod region;
+pub mod name;
+pub mod layout;
+pub mod layer;
+pub mod color;
+pub mod colormap;
+pub mod choropleth;
+pub mod history;
+pub mod order;
+pub mod selector;
+
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::slice::{Iter, IterMut};
+use smart_leds::RGB8;
+use crate::map::choropleth::{Choropleth, Value};
+use crate::map::district::{Bounds, Coordinates, District};
+use crate::map::history::{History, Snapshot};
+use crate::map::layer::{BlendMode, Layer};
+use crate::map::layout::Layout;
+use crate::map::name::Named;
+use crate::map::order::Order;
+use crate::map::region::Region;
+
+/// Index of the layer animations are drawn to.
+pub const ANIMATION_LAYER: usize = 0;
+/// Index of the topmost layer, for notifications shown over everything else.
+pub const OVERLAY_LAYER: usize = 1;
+
+/// The LEDs of the board. The colors set on the map are the base layer,
+/// the layers are drawn over it, see `frame`.
+pub struct Map<'d> {
+    layout: &'d Layout,
+    bounds: Bounds,
+    data: &'d mut [RGB8],
+    /// LEDs drawn to when the map draws to a layer, see `layer_map`.
+    opaque: Option<&'d mut [bool]>,
+    layers: Vec<Layer>,
+    choropleth: Choropleth,
+    history: History,
+}
+
+pub enum Error {
+    NotFound,
+    /// The name is a prefix of several names.
+    Ambiguous,
+}
+
+impl<'d> Map<'d> {
+    /// The data have to hold an RGB value for every district of the layout.
+    pub fn new(layout: &'d Layout, data: &'d mut [RGB8]) -> Self {
+        assert_eq!(layout.leds_count(), data.len(), "Data do not match the layout.");
+
+        Map {
+            layout,
+            bounds: Bounds::of(layout.districts),
+            data,
+            opaque: None,
+            layers: vec![
+                Layer::new(layout.leds_count(), BlendMode::Replace),
+                Layer::new(layout.leds_count(), BlendMode::Replace),
+            ],
+            choropleth: Choropleth::new(layout.leds_count()),
+            history: History::new(),
+        }
+    }
+
+    pub fn layout(&self) -> &'d Layout {
+        self.layout
+    }
+
+    pub fn districts(&self) -> &'d [District] {
+        self.layout.districts
+    }
+
+    pub fn regions(&self) -> &'d [Region] {
+        self.layout.regions
+    }
+
+    pub fn district(&self, index: usize) -> Result<&'d District, Error> {
+        self.layout.districts.get(index).ok_or(Error::NotFound)
+    }
+
+    pub fn coordinates(&self, index: usize) -> Result<Coordinates, Error> {
+        Ok(self.district(index)?.coordinates)
+    }
+
+    /// Position of the LED on the board normalized to 0.0 - 1.0,
+    /// x goes from west to east, y from north to south.
+    pub fn position(&self, index: usize) -> Result<(f32, f32), Error> {
+        Ok(self.bounds.normalize(self.coordinates(index)?))
+    }
+
+    /// District whose centre is the closest to the coordinates,
+    /// the district containing them unless they are close to its border.
+    pub fn nearest(&self, coordinates: Coordinates) -> Result<usize, Error> {
+        self.layout.districts.iter()
+            .map(|district| district.coordinates.distance(coordinates))
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(index, _)| index)
+            .ok_or(Error::NotFound)
+    }
+
+    /// Indices of the districts with their centre at most `kilometers` from the coordinates.
+    pub fn within_distance(&self, coordinates: Coordinates, kilometers: f32) -> Vec<usize> {
+        self.layout.districts.iter()
+            .enumerate()
+            .filter(|(_, district)| district.coordinates.distance(coordinates) <= kilometers)
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Indices of all the districts in the given order.
+    pub fn order(&self, order: Order) -> Result<Vec<usize>, Error> {
+        order.indices(self)
+    }
+
+    pub fn bounds(&self) -> Bounds {
+        self.bounds
+    }
+
+    /// Indices of the districts sharing a border with the given one.
+    pub fn neighbours(&self, index: usize) -> impl Iterator<Item=usize> + 'd {
+        self.layout.borders.iter()
+            .filter_map(move |&(a, b)| {
+                if a == index {
+                    Some(b)
+                } else if b == index {
+                    Some(a)
+                } else {
+                    None
+                }
+            })
+    }
+
+    pub fn are_neighbours(&self, a: usize, b: usize) -> bool {
+        self.neighbours(a).any(|neighbour| neighbour == b)
+    }
+
+    /// Number of borders to cross from the given district to each of the districts,
+    /// `None` for the districts that cannot be reached.
+    pub fn distances(&self, from: usize) -> Result<Vec<Option<usize>>, Error> {
+        self.district(from)?;
+
+        let mut distances = vec![None; self.layout.districts.len()];
+        let mut queue = VecDeque::new();
+        distances[from] = Some(0);
+        queue.push_back(from);
+
+        while let Some(current) = queue.pop_front() {
+            let distance = distances[current].unwrap_or(0) + 1;
+            for neighbour in self.neighbours(current) {
+                if distances[neighbour].is_none() {
+                    distances[neighbour] = Some(distance);
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+
+        Ok(distances)
+    }
+
+    /// Number of borders to cross to get from one district to the other.
+    pub fn distance(&self, from: usize, to: usize) -> Result<Option<usize>, Error> {
+        self.district(to)?;
+        Ok(self.distances(from)?[to])
+    }
+
+    /// Indices of the districts at most `hops` borders away from the given one,
+    /// including the district itself.
+    pub fn within_hops(&self, from: usize, hops: usize) -> Result<Vec<usize>, Error> {
+        Ok(self.distances(from)?
+            .iter()
+            .enumerate()
+            .filter(|(_, distance)| distance.is_some_and(|distance| distance <= hops))
+            .map(|(i, _)| i)
+            .collect())
+    }
+
+    /// Finds the name ignoring case and diacritics, then the aliases.
+    /// If there is no such name, a unique name starting with it is accepted.
+    fn find_by_name<T: Named>(items: &[T], name: &[char]) -> Result<usize, Error> {
+        if name.is_empty() {
+            return Err(Error::NotFound);
+        }
+
+        if let Some(index) = items.iter().position(|item| name::matches(item.name(), name)) {
+            return Ok(index);
+        }
+
+        if let Some(index) = items.iter().position(|item| item.aliases().iter().any(|alias| name::matches(alias, name))) {
+            return Ok(index);
+        }
+
+        let mut prefixed = items.iter()
+            .enumerate()
+            .filter(|(_, item)| name::starts_with(item.name(), name))
+            .map(|(i, _)| i);
+
+        match (prefixed.next(), prefixed.next()) {
+            (Some(index), None) => Ok(index),
+            (Some(_), Some(_)) => Err(Error::Ambiguous),
+            _ => Err(Error::NotFound)
+        }
+    }
+
+    pub fn get_index_by_name(&self, name: &[char]) -> Result<usize, Error> {
+        Self::find_by_name(self.layout.districts, name)
+    }
+
+    /// Indices of the LEDs given by a selector such as `0-10`, `PRAHA,BRNO`, `*HRADEC*`, `*`
+    /// or `!PRAHA`, see `selector::resolve`.
+    pub fn select(&self, selector: &[char]) -> Result<Vec<usize>, Error> {
+        selector::resolve(self, selector)
+    }
+
+    pub fn get_region_by_name(&self, name: &[char]) -> Result<usize, Error> {
+        Self::find_by_name(self.layout.regions, name)
+    }
+
+    /// Indices of at most `count` districts with names similar to the given one,
+    /// the closest first. Meant for suggestions when the name is not found.
+    pub fn suggest(&self, name: &[char], count: usize) -> Vec<usize> {
+        let mut suggestions: Vec<(usize, usize)> = self.layout.districts.iter()
+            .enumerate()
+            .filter_map(|(i, district)| name::similarity(district.name, name).map(|similarity| (similarity, i)))
+            .collect();
+
+        suggestions.sort();
+        suggestions.into_iter()
+            .take(count)
+            .map(|(_, i)| i)
+            .collect()
+    }
+
+    /// Indices of all the districts in the given region.
+    pub fn region_districts(&self, region: usize) -> impl Iterator<Item=usize> + 'd {
+        self.layout.districts.iter()
+            .enumerate()
+            .filter(move |(_, district)| district.region == region)
+            .map(|(i, _)| i)
+    }
+
+    pub fn set(&mut self, index: usize, rgb: RGB8) -> Result<(), Error> {
+        self.data[index] = rgb;
+        if let Some(opaque) = self.opaque.as_deref_mut() {
+            opaque[index] = true;
+        }
+        Ok(())
+    }
+
+    pub fn set_rgb(&mut self, index: usize, r: Option<u8>, g: Option<u8>, b: Option<u8>) -> Result<(), Error> {
+        if self.data.len() <= index {
+            return Err(Error::NotFound)
+        }
+        let original = self.data[index];
+
+        self.set(index, RGB8 {
+            r: r.unwrap_or(original.r),
+            g: g.unwrap_or(original.g),
+            b: b.unwrap_or(original.b),
+        })
+    }
+
+    pub fn set_rgb_by_name(&mut self, name: &[char], r: Option<u8>, g: Option<u8>, b: Option<u8>) -> Result<(), Error> {
+        let index = self.get_index_by_name(name)?;
+        self.set_rgb(index, r, g, b)
+    }
+
+    pub fn set_region_rgb(&mut self, region: usize, r: Option<u8>, g: Option<u8>, b: Option<u8>) -> Result<(), Error> {
+        if self.layout.regions.len() <= region {
+            return Err(Error::NotFound)
+        }
+
+        for index in self.region_districts(region) {
+            self.set_rgb(index, r, g, b)?;
+        }
+
+        Ok(())
+    }
+
+    /// Turns all the LEDs off, on a layer map they become transparent.
+    pub fn clear(&mut self) {
+        self.data.fill(RGB8 { r: 0, g: 0, b: 0 });
+        if let Some(opaque) = self.opaque.as_deref_mut() {
+            opaque.fill(false);
+        }
+    }
+
+    pub fn layers(&self) -> &[Layer] {
+        &self.layers
+    }
+
+    pub fn layer(&self, index: usize) -> Result<&Layer, Error> {
+        self.layers.get(index).ok_or(Error::NotFound)
+    }
+
+    pub fn layer_mut(&mut self, index: usize) -> Result<&mut Layer, Error> {
+        self.layers.get_mut(index).ok_or(Error::NotFound)
+    }
+
+    /// Adds a layer on top of the others, returns its index.
+    pub fn add_layer(&mut self, blend_mode: BlendMode) -> usize {
+        self.layers.push(Layer::new(self.layout.leds_count(), blend_mode));
+        self.layers.len() - 1
+    }
+
+    pub fn clear_layers(&mut self) {
+        for layer in &mut self.layers {
+            layer.clear();
+        }
+    }
+
+    /// Map drawing to the given layer instead of the base colors,
+    /// the returned map has no layers of its own. The LEDs it sets become opaque
+    /// and clearing it makes the whole layer transparent again.
+    pub fn layer_map(&mut self, index: usize) -> Result<Map<'_>, Error> {
+        let (data, opaque) = self.layers.get_mut(index).ok_or(Error::NotFound)?.data_mut();
+
+        Ok(Map {
+            layout: self.layout,
+            bounds: self.bounds,
+            data,
+            opaque: Some(opaque),
+            layers: Vec::new(),
+            choropleth: Choropleth::new(0),
+            history: History::new(),
+        })
+    }
+
+    pub fn choropleth(&self) -> &Choropleth {
+        &self.choropleth
+    }
+
+    pub fn choropleth_mut(&mut self) -> &mut Choropleth {
+        &mut self.choropleth
+    }
+
+    /// Sets the districts having a value to their color from the choropleth,
+    /// to be called after the values or the scale change.
+    pub fn render_choropleth(&mut self) {
+        for (index, color) in self.choropleth.colors() {
+            self.data[index] = color;
+        }
+    }
+
+    /// Removes the value of the district and turns its LED off if it had one.
+    /// Returns false if the district had no value.
+    pub fn clear_value(&mut self, index: usize) -> bool {
+        if self.choropleth.value(index).is_none() {
+            return false;
+        }
+
+        self.choropleth.set_value(index, None);
+        if let Some(led) = self.data.get_mut(index) {
+            *led = RGB8::default();
+        }
+        true
+    }
+
+    /// Removes all the categories, turns the LEDs of the districts assigned to them off
+    /// and returns how many districts were.
+    pub fn clear_categories(&mut self) -> usize {
+        let cleared = (0..self.data.len())
+            .filter(|index| matches!(self.choropleth.value(*index), Some(Value::Category(_))) && self.clear_value(*index))
+            .count();
+        self.choropleth.clear_categories();
+        self.render_choropleth();
+        cleared
+    }
+
+    /// Removes all the values and turns their LEDs off, returns how many values were removed.
+    pub fn clear_choropleth(&mut self) -> usize {
+        (0..self.data.len()).filter(|index| self.clear_value(*index)).count()
+    }
+
+    pub fn history(&self) -> &History {
+        &self.history
+    }
+
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            colors: self.data.to_vec(),
+            choropleth: self.choropleth.clone(),
+        }
+    }
+
+    pub fn restore(&mut self, snapshot: Snapshot) {
+        self.data.copy_from_slice(&snapshot.colors);
+        self.choropleth = snapshot.choropleth;
+    }
+
+    /// Adds the state before a change to the history if the map has changed since.
+    pub fn record(&mut self, before: Snapshot) {
+        if before != self.snapshot() {
+            self.history.push(before);
+        }
+    }
+
+    /// Returns false if there is nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        match self.history.undo(self.snapshot()) {
+            Some(previous) => {
+                self.restore(previous);
+                true
+            }
+            None => false
+        }
+    }
+
+    /// Returns false if there is nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        match self.history.redo(self.snapshot()) {
+            Some(next) => {
+                self.restore(next);
+                true
+            }
+            None => false
+        }
+    }
+
+    /// Colors of the LEDs with all the layers blended over the base colors,
+    /// this is what should be shown on the board.
+    pub fn frame(&self) -> impl Iterator<Item=RGB8> + Clone + '_ {
+        self.data.iter()
+            .enumerate()
+            .map(|(index, rgb)| self.layers.iter().fold(*rgb, |below, layer| layer.blend(index, below)))
+    }
+
+    /// Base colors of the LEDs, without the layers.
+    pub fn get_map(&self) -> Iter<'_, RGB8> {
+        self.data.iter()
+    }
+    /// On a layer map all the LEDs become opaque.
+    pub fn get_map_mut(&mut self) -> IterMut<'_, RGB8> {
+        if let Some(opaque) = self.opaque.as_deref_mut() {
+            opaque.fill(true);
+        }
+        self.data.iter_mut()
+    }
+}
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use smart_leds::RGB8;
+    use crate::map::choropleth::Value;
+    use crate::map::district::Coordinates;
+    use crate::map::layout::LAYOUT;
+    use super::{Error, Map, ANIMATION_LAYER};
+
+    const RED: RGB8 = RGB8 { r: 255, g: 0, b: 0 };
+    const BLACK: RGB8 = RGB8 { r: 0, g: 0, b: 0 };
+
+    fn chars(name: &str) -> Vec<char> {
+        name.chars().collect()
+    }
+
+    /// Name of the district found by the given name.
+    fn find(map: &Map, name: &str) -> Result<&'static str, Error> {
+        map.get_index_by_name(&chars(name)).map(|index| LAYOUT.districts[index].name)
+    }
+
+    #[test]
+    fn black_on_a_layer_is_drawn() {
+        let mut data = vec![RED; LAYOUT.leds_count()];
+        let mut map = Map::new(&LAYOUT, &mut data);
+
+        map.layer_map(ANIMATION_LAYER).ok().unwrap().set(0, BLACK).ok().unwrap();
+
+        let frame: Vec<RGB8> = map.frame().collect();
+        assert_eq!(frame[0], BLACK);
+        assert!(frame[1..].iter().all(|rgb| *rgb == RED));
+    }
+
+    #[test]
+    fn cleared_layer_is_transparent() {
+        let mut data = vec![RED; LAYOUT.leds_count()];
+        let mut map = Map::new(&LAYOUT, &mut data);
+
+        let mut layer = map.layer_map(ANIMATION_LAYER).ok().unwrap();
+        layer.get_map_mut().for_each(|rgb| *rgb = BLACK);
+        assert!(map.frame().all(|rgb| rgb == BLACK));
+
+        map.layer_map(ANIMATION_LAYER).ok().unwrap().clear();
+        assert!(map.frame().all(|rgb| rgb == RED));
+
+        map.layer_map(ANIMATION_LAYER).ok().unwrap().set(0, BLACK).ok().unwrap();
+        map.clear_layers();
+        assert!(map.frame().all(|rgb| rgb == RED));
+    }
+
+    #[test]
+    fn finds_districts_by_name() {
+        let mut data = vec![BLACK; LAYOUT.leds_count()];
+        let map = Map::new(&LAYOUT, &mut data);
+
+        assert!(matches!(find(&map, "praha"), Ok("PRAHA")));
+        assert!(matches!(find(&map, "Ústí nad Labem"), Ok("ÚSTI_NAD_LABEM")));
+        assert!(matches!(find(&map, "PRAGUE"), Ok("PRAHA")));
+        assert!(matches!(find(&map, "CZ0323"), Ok("PLZEN")));
+        assert!(matches!(find(&map, "HRADEC_K"), Ok("HRADEC_KRALOVE")));
+        assert!(matches!(find(&map, "CESK"), Err(Error::Ambiguous)));
+        assert!(matches!(find(&map, "NOWHERE"), Err(Error::NotFound)));
+        assert!(matches!(find(&map, ""), Err(Error::NotFound)));
+    }
+
+    #[test]
+    fn aliases_win_over_beginnings() {
+        let mut data = vec![BLACK; LAYOUT.leds_count()];
+        let map = Map::new(&LAYOUT, &mut data);
+
+        // BR is the plate code of Bruntál, although BRNO and BRECLAV start with it
+        assert!(matches!(find(&map, "BR"), Ok("BRUNTAL")));
+        assert!(matches!(find(&map, "BRE"), Ok("BRECLAV")));
+    }
+
+    #[test]
+    fn suggests_similar_districts() {
+        let mut data = vec![BLACK; LAYOUT.leds_count()];
+        let map = Map::new(&LAYOUT, &mut data);
+
+        let suggestions = map.suggest(&chars("OLOMUC"), 3);
+        assert_eq!(LAYOUT.districts[suggestions[0]].name, "OLOMOUC");
+        assert!(map.suggest(&chars("XXXXXXXX"), 3).is_empty());
+    }
+
+    #[test]
+    fn counts_borders_to_cross() {
+        let mut data = vec![BLACK; LAYOUT.leds_count()];
+        let map = Map::new(&LAYOUT, &mut data);
+        let praha = map.get_index_by_name(&chars("PRAHA")).ok().unwrap();
+
+        let distances = map.distances(praha).ok().unwrap();
+        assert_eq!(distances[praha], Some(0));
+        assert!(map.neighbours(praha).all(|neighbour| distances[neighbour] == Some(1)));
+        assert!(distances.iter().all(|distance| distance.is_some()));
+        // neighbours are never more than one border further from the start than each other
+        for &(a, b) in LAYOUT.borders {
+            assert!(distances[a].unwrap().abs_diff(distances[b].unwrap()) <= 1);
+        }
+
+        let brno = map.get_index_by_name(&chars("BRNO")).ok().unwrap();
+        assert_eq!(map.distance(praha, brno).ok().flatten(), distances[brno]);
+        assert_eq!(map.distance(brno, praha).ok().flatten(), distances[brno]);
+        assert!(map.distances(LAYOUT.leds_count()).is_err());
+    }
+
+    #[test]
+    fn selects_districts_within_hops() {
+        let mut data = vec![BLACK; LAYOUT.leds_count()];
+        let map = Map::new(&LAYOUT, &mut data);
+        let praha = map.get_index_by_name(&chars("PRAHA")).ok().unwrap();
+
+        assert_eq!(map.within_hops(praha, 0).ok(), Some(vec![praha]));
+
+        let mut expected: Vec<usize> = map.neighbours(praha).chain([praha]).collect();
+        expected.sort();
+        assert_eq!(map.within_hops(praha, 1).ok(), Some(expected));
+        assert_eq!(map.within_hops(praha, usize::MAX).ok().map(|all| all.len()), Some(LAYOUT.leds_count()));
+    }
+
+    #[test]
+    fn finds_nearest_district() {
+        let mut data = vec![BLACK; LAYOUT.leds_count()];
+        let map = Map::new(&LAYOUT, &mut data);
+
+        for (index, district) in LAYOUT.districts.iter().enumerate() {
+            assert_eq!(map.nearest(district.coordinates).ok(), Some(index));
+        }
+        // Špilberk castle in Brno
+        assert!(matches!(map.nearest(Coordinates::new(49.194, 16.600)).map(|index| LAYOUT.districts[index].name), Ok("BRNO")));
+    }
+
+    #[test]
+    fn selects_districts_within_distance() {
+        let mut data = vec![BLACK; LAYOUT.leds_count()];
+        let map = Map::new(&LAYOUT, &mut data);
+        let praha = map.get_index_by_name(&chars("PRAHA")).ok().unwrap();
+        let center = LAYOUT.districts[praha].coordinates;
+
+        assert_eq!(map.within_distance(center, 0.0), vec![praha]);
+
+        let near = map.within_distance(center, 60.0);
+        for (index, district) in LAYOUT.districts.iter().enumerate() {
+            assert_eq!(near.contains(&index), district.coordinates.distance(center) <= 60.0);
+        }
+        assert!(near.contains(&map.get_index_by_name(&chars("KLADNO")).ok().unwrap()));
+        assert!(!near.contains(&map.get_index_by_name(&chars("BRNO")).ok().unwrap()));
+
+        assert_eq!(map.within_distance(center, 1000.0).len(), LAYOUT.leds_count());
+        assert!(map.within_distance(Coordinates::new(0.0, 0.0), 100.0).is_empty());
+    }
+
+    #[test]
+    fn undoes_recorded_changes() {
+        let mut data = vec![BLACK; LAYOUT.leds_count()];
+        let mut map = Map::new(&LAYOUT, &mut data);
+
+        let before = map.snapshot();
+        map.record(before);
+        assert!(!map.undo(), "unchanged map is not recorded");
+
+        let before = map.snapshot();
+        map.set(0, RED).ok().unwrap();
+        map.record(before);
+
+        assert!(map.undo());
+        assert_eq!(map.get_map().next(), Some(&BLACK));
+        assert!(map.redo());
+        assert_eq!(map.get_map().next(), Some(&RED));
+        assert!(!map.redo());
+    }
+
+    #[test]
+    fn cleared_categories_turn_their_leds_off() {
+        let mut data = vec![BLACK; LAYOUT.leds_count()];
+        let mut map = Map::new(&LAYOUT, &mut data);
+
+        let category = map.choropleth_mut().set_category(&chars("HIGH"), RED);
+        map.choropleth_mut().set_value(0, Some(Value::Category(category)));
+        map.choropleth_mut().set_value(1, Some(Value::Category(category)));
+        map.choropleth_mut().set_value(2, Some(Value::Number(1.0)));
+        map.render_choropleth();
+        let number = map.get_map().nth(2).copied();
+
+        assert_eq!(map.clear_categories(), 2);
+        assert!(map.get_map().take(2).all(|rgb| *rgb == BLACK));
+        assert_eq!(map.get_map().nth(2).copied(), number);
+        assert!(map.choropleth().categories().is_empty());
+        assert_eq!(map.clear_categories(), 0);
+    }
+
+    #[test]
+    fn cleared_values_turn_their_leds_off() {
+        let mut data = vec![BLACK; LAYOUT.leds_count()];
+        let mut map = Map::new(&LAYOUT, &mut data);
+
+        map.choropleth_mut().set_value(0, Some(Value::Number(1.0)));
+        map.choropleth_mut().set_value(1, Some(Value::Number(2.0)));
+        map.choropleth_mut().set_value(2, Some(Value::Number(3.0)));
+        map.render_choropleth();
+        assert!(map.get_map().take(3).all(|rgb| *rgb != BLACK));
+
+        assert!(map.clear_value(0));
+        assert!(!map.clear_value(0));
+        assert_eq!(map.get_map().next(), Some(&BLACK));
+
+        assert_eq!(map.clear_choropleth(), 2);
+        assert!(map.get_map().all(|rgb| *rgb == BLACK));
+        assert_eq!(map.clear_choropleth(), 0);
+    }
+}
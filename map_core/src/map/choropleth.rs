@@ -0,0 +1,376 @@
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use libm::{expf, logf};
+use smart_leds::RGB8;
+use crate::map::colormap::Colormap;
+use crate::map::name;
+
+/// Value assigned to a district.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Value {
+    Number(f32),
+    /// Index of the category.
+    Category(usize),
+}
+
+/// Numbers classed by thresholds, `colors` has one more item than `thresholds`.
+/// Numbers below the first threshold get the first color,
+/// numbers at or above the last threshold get the last one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Classes {
+    thresholds: Vec<f32>,
+    colors: Vec<RGB8>,
+}
+
+impl Classes {
+    /// Returns `None` if the thresholds are not ascending
+    /// or the number of the colors does not match.
+    pub fn new(thresholds: Vec<f32>, colors: Vec<RGB8>) -> Option<Self> {
+        if colors.len() != thresholds.len() + 1 || thresholds.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return None;
+        }
+
+        Some(Self {
+            thresholds,
+            colors,
+        })
+    }
+
+    pub fn thresholds(&self) -> &[f32] {
+        &self.thresholds
+    }
+
+    pub fn colors(&self) -> &[RGB8] {
+        &self.colors
+    }
+
+    /// Index of the class of the number.
+    pub fn class(&self, number: f32) -> usize {
+        self.thresholds.iter().take_while(|threshold| number >= **threshold).count()
+    }
+}
+
+/// Values assigned to the districts, numbers are shown as colors
+/// of a colormap or of their class, categories by their own colors.
+#[derive(Clone, PartialEq)]
+pub struct Choropleth {
+    values: Vec<Option<Value>>,
+    colormap: Colormap,
+    classes: Option<Classes>,
+    categories: Vec<(String, RGB8)>,
+    min: Option<f32>,
+    max: Option<f32>,
+    logarithmic: bool,
+}
+
+impl Choropleth {
+    pub fn new(leds_count: usize) -> Self {
+        Self {
+            values: vec![None; leds_count],
+            colormap: Colormap::Viridis,
+            classes: None,
+            categories: Vec::new(),
+            min: None,
+            max: None,
+            logarithmic: false,
+        }
+    }
+
+    pub fn value(&self, index: usize) -> Option<Value> {
+        self.values.get(index).copied().flatten()
+    }
+
+    /// Returns false if there is no such district or category.
+    pub fn set_value(&mut self, index: usize, value: Option<Value>) -> bool {
+        if let Some(Value::Category(category)) = value {
+            if category >= self.categories.len() {
+                return false;
+            }
+        }
+
+        match self.values.get_mut(index) {
+            Some(current) => {
+                *current = value;
+                true
+            }
+            None => false
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.values.fill(None);
+    }
+
+    /// Districts having the value.
+    pub fn count(&self, value: Value) -> usize {
+        self.values.iter().filter(|current| **current == Some(value)).count()
+    }
+
+    /// Classes of the numbers, `None` if they are shown using the colormap.
+    pub fn classes(&self) -> Option<&Classes> {
+        self.classes.as_ref()
+    }
+
+    pub fn set_classes(&mut self, classes: Option<Classes>) {
+        self.classes = classes;
+    }
+
+    pub fn categories(&self) -> &[(String, RGB8)] {
+        &self.categories
+    }
+
+    /// Adds the category or changes the color of an existing one,
+    /// returns its index.
+    pub fn set_category(&mut self, label: &[char], color: RGB8) -> usize {
+        if let Some(index) = self.find_category(label) {
+            self.categories[index].1 = color;
+            return index;
+        }
+
+        self.categories.push((label.iter().map(|c| name::fold(*c)).collect(), color));
+        self.categories.len() - 1
+    }
+
+    /// Index of the category with the label, compared the same way as the names of the districts.
+    pub fn find_category(&self, label: &[char]) -> Option<usize> {
+        self.categories.iter().position(|(current, _)| name::matches(current, label))
+    }
+
+    /// Removes all the categories and the values assigned to them.
+    pub fn clear_categories(&mut self) {
+        for value in self.values.iter_mut() {
+            if let Some(Value::Category(_)) = value {
+                *value = None;
+            }
+        }
+        self.categories.clear();
+    }
+
+    pub fn colormap(&self) -> &Colormap {
+        &self.colormap
+    }
+
+    pub fn set_colormap(&mut self, colormap: Colormap) {
+        self.colormap = colormap;
+    }
+
+    /// Limits of the scale, `None` for the limits taken from the values.
+    pub fn limits(&self) -> (Option<f32>, Option<f32>) {
+        (self.min, self.max)
+    }
+
+    pub fn set_limits(&mut self, min: Option<f32>, max: Option<f32>) {
+        self.min = min;
+        self.max = max;
+    }
+
+    pub fn is_logarithmic(&self) -> bool {
+        self.logarithmic
+    }
+
+    pub fn set_logarithmic(&mut self, logarithmic: bool) {
+        self.logarithmic = logarithmic;
+    }
+
+    /// The lowest and the highest value of the scale.
+    pub fn range(&self) -> Option<(f32, f32)> {
+        let values = self.numbers();
+        let min = self.min.or_else(|| values.clone().reduce(f32::min))?;
+        let max = self.max.or_else(|| values.reduce(f32::max))?;
+        Some((min, max))
+    }
+
+    /// Position of the value on the scale, 0.0 for the minimum and 1.0 for the maximum.
+    pub fn position(&self, value: f32) -> f32 {
+        let (min, max) = match self.range() {
+            Some(range) => range,
+            None => return 0.0
+        };
+
+        let (value, min, max) = if self.logarithmic {
+            if value <= 0.0 || min <= 0.0 || max <= 0.0 {
+                return 0.0;
+            }
+            (logf(value), logf(min), logf(max))
+        } else {
+            (value, min, max)
+        };
+
+        if max <= min {
+            return 0.0;
+        }
+
+        ((value - min) / (max - min)).clamp(0.0, 1.0)
+    }
+
+    /// Value at the position of the scale, the inverse of `position`.
+    /// `None` if there are no limits, or they are not positive on the logarithmic scale.
+    pub fn value_at(&self, position: f32) -> Option<f32> {
+        let (min, max) = self.range()?;
+
+        if !self.logarithmic {
+            return Some(min + (max - min) * position);
+        }
+
+        if min <= 0.0 || max <= 0.0 {
+            return None;
+        }
+        // the limits exactly, without the rounding of the logarithms
+        match position {
+            position if position <= 0.0 => Some(min),
+            position if position >= 1.0 => Some(max),
+            position => Some(expf(logf(min) + (logf(max) - logf(min)) * position)),
+        }
+    }
+
+    pub fn color(&self, value: Value) -> RGB8 {
+        match value {
+            Value::Number(number) => match &self.classes {
+                Some(classes) => classes.colors[classes.class(number)],
+                None => self.colormap.color(self.position(number))
+            },
+            Value::Category(category) => self.categories[category].1,
+        }
+    }
+
+    fn numbers(&self) -> impl Iterator<Item=f32> + Clone + '_ {
+        self.values.iter().filter_map(|value| match value {
+            Some(Value::Number(number)) => Some(*number),
+            _ => None
+        })
+    }
+
+    /// Districts having a value with their color.
+    pub fn colors(&self) -> impl Iterator<Item=(usize, RGB8)> + '_ {
+        self.values.iter()
+            .enumerate()
+            .filter_map(|(index, value)| value.map(|value| (index, self.color(value))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use smart_leds::RGB8;
+    use super::{Choropleth, Classes, Value};
+
+    fn choropleth(numbers: &[f32]) -> Choropleth {
+        let mut choropleth = Choropleth::new(numbers.len() + 1);
+        for (index, number) in numbers.iter().enumerate() {
+            choropleth.set_value(index, Some(Value::Number(*number)));
+        }
+        choropleth
+    }
+
+    #[test]
+    fn scales_between_the_values() {
+        let choropleth = choropleth(&[10.0, 20.0, 30.0]);
+
+        assert_eq!(choropleth.range(), Some((10.0, 30.0)));
+        assert_eq!(choropleth.position(10.0), 0.0);
+        assert_eq!(choropleth.position(15.0), 0.25);
+        assert_eq!(choropleth.position(30.0), 1.0);
+        assert_eq!(choropleth.position(50.0), 1.0);
+        assert_eq!(choropleth.position(0.0), 0.0);
+    }
+
+    #[test]
+    fn limits_override_the_values() {
+        let mut choropleth = choropleth(&[10.0, 20.0, 30.0]);
+
+        choropleth.set_limits(Some(0.0), None);
+        assert_eq!(choropleth.range(), Some((0.0, 30.0)));
+        assert_eq!(choropleth.position(15.0), 0.5);
+
+        choropleth.set_limits(Some(0.0), Some(100.0));
+        assert_eq!(choropleth.position(30.0), 0.3);
+    }
+
+    #[test]
+    fn scales_logarithmically() {
+        let mut choropleth = choropleth(&[1.0, 10.0, 100.0]);
+        choropleth.set_logarithmic(true);
+
+        assert!((choropleth.position(10.0) - 0.5).abs() < 1e-6);
+        assert_eq!(choropleth.position(100.0), 1.0);
+        assert_eq!(choropleth.position(0.0), 0.0);
+        assert_eq!(choropleth.position(-5.0), 0.0);
+
+        choropleth.set_limits(Some(0.0), None);
+        assert_eq!(choropleth.position(10.0), 0.0);
+    }
+
+    #[test]
+    fn finds_values_on_the_scale() {
+        let mut choropleth = choropleth(&[1.0, 100.0]);
+        assert_eq!(choropleth.value_at(0.0), Some(1.0));
+        assert_eq!(choropleth.value_at(0.5), Some(50.5));
+        assert_eq!(choropleth.value_at(1.0), Some(100.0));
+
+        choropleth.set_logarithmic(true);
+        let middle = choropleth.value_at(0.5).unwrap();
+        assert!((middle - 10.0).abs() < 1e-3);
+        assert!((choropleth.position(middle) - 0.5).abs() < 1e-6);
+        assert_eq!(choropleth.value_at(0.0), Some(1.0));
+        assert_eq!(choropleth.value_at(1.0), Some(100.0));
+
+        choropleth.set_limits(Some(0.0), None);
+        assert_eq!(choropleth.value_at(0.5), None);
+        assert_eq!(Choropleth::new(3).value_at(0.5), None);
+    }
+
+    #[test]
+    fn handles_degenerate_ranges() {
+        assert_eq!(Choropleth::new(3).range(), None);
+        assert_eq!(Choropleth::new(3).position(1.0), 0.0);
+        assert_eq!(choropleth(&[5.0, 5.0]).position(5.0), 0.0);
+    }
+
+    #[test]
+    fn colors_the_values_by_the_colormap() {
+        let choropleth = choropleth(&[0.0, 1.0]);
+        let colormap = choropleth.colormap();
+
+        let colors: Vec<_> = choropleth.colors().collect();
+        assert_eq!(colors, [(0, colormap.color(0.0)), (1, colormap.color(1.0))]);
+    }
+
+    #[test]
+    fn classes_numbers_by_thresholds() {
+        let colors = vec![RGB8 { r: 1, g: 0, b: 0 }, RGB8 { r: 2, g: 0, b: 0 }, RGB8 { r: 3, g: 0, b: 0 }];
+        assert!(Classes::new(vec![10.0, 20.0], colors[..2].to_vec()).is_none());
+        assert!(Classes::new(vec![20.0, 10.0], colors.clone()).is_none());
+        assert!(Classes::new(vec![10.0, 10.0], colors.clone()).is_none());
+
+        let classes = Classes::new(vec![10.0, 20.0], colors.clone()).unwrap();
+        assert_eq!(classes.class(-1.0), 0);
+        assert_eq!(classes.class(10.0), 1);
+        assert_eq!(classes.class(19.9), 1);
+        assert_eq!(classes.class(20.0), 2);
+
+        let mut choropleth = choropleth(&[5.0, 15.0, 25.0]);
+        choropleth.set_classes(Some(classes));
+        let shown: Vec<RGB8> = choropleth.colors().map(|(_, color)| color).collect();
+        assert_eq!(shown, colors);
+    }
+
+    #[test]
+    fn categories_are_found_like_names() {
+        let mut choropleth = Choropleth::new(2);
+        let red = RGB8 { r: 255, g: 0, b: 0 };
+        let label: Vec<char> = "Vysoká".chars().collect();
+
+        let index = choropleth.set_category(&label, RGB8::default());
+        assert_eq!(choropleth.set_category(&"VYSOKA".chars().collect::<Vec<char>>(), red), index);
+        assert_eq!(choropleth.categories().len(), 1);
+
+        assert!(!choropleth.set_value(0, Some(Value::Category(index + 1))));
+        assert!(choropleth.set_value(0, Some(Value::Category(index))));
+        assert_eq!(choropleth.color(Value::Category(index)), red);
+
+        choropleth.clear_categories();
+        assert_eq!(choropleth.value(0), None);
+    }
+}
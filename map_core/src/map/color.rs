@@ -0,0 +1,265 @@
+use libm::{fabsf, fmodf, logf, powf, roundf};
+use smart_leds::RGB8;
+
+/// Lowest color temperature in kelvins `kelvin` gives a color for.
+pub const MIN_KELVIN: u32 = 1000;
+/// Highest color temperature in kelvins `kelvin` gives a color for.
+pub const MAX_KELVIN: u32 = 40000;
+
+/// CSS colors accepted by name.
+pub const NAMED_COLORS: [(&str, RGB8); 40] = [
+    ("BLACK", RGB8 { r: 0, g: 0, b: 0 }),
+    ("WHITE", RGB8 { r: 255, g: 255, b: 255 }),
+    ("RED", RGB8 { r: 255, g: 0, b: 0 }),
+    ("LIME", RGB8 { r: 0, g: 255, b: 0 }),
+    ("GREEN", RGB8 { r: 0, g: 128, b: 0 }),
+    ("BLUE", RGB8 { r: 0, g: 0, b: 255 }),
+    ("YELLOW", RGB8 { r: 255, g: 255, b: 0 }),
+    ("CYAN", RGB8 { r: 0, g: 255, b: 255 }),
+    ("AQUA", RGB8 { r: 0, g: 255, b: 255 }),
+    ("MAGENTA", RGB8 { r: 255, g: 0, b: 255 }),
+    ("FUCHSIA", RGB8 { r: 255, g: 0, b: 255 }),
+    ("ORANGE", RGB8 { r: 255, g: 165, b: 0 }),
+    ("PURPLE", RGB8 { r: 128, g: 0, b: 128 }),
+    ("PINK", RGB8 { r: 255, g: 192, b: 203 }),
+    ("TEAL", RGB8 { r: 0, g: 128, b: 128 }),
+    ("NAVY", RGB8 { r: 0, g: 0, b: 128 }),
+    ("MAROON", RGB8 { r: 128, g: 0, b: 0 }),
+    ("OLIVE", RGB8 { r: 128, g: 128, b: 0 }),
+    ("SILVER", RGB8 { r: 192, g: 192, b: 192 }),
+    ("GRAY", RGB8 { r: 128, g: 128, b: 128 }),
+    ("GREY", RGB8 { r: 128, g: 128, b: 128 }),
+    ("BROWN", RGB8 { r: 165, g: 42, b: 42 }),
+    ("GOLD", RGB8 { r: 255, g: 215, b: 0 }),
+    ("INDIGO", RGB8 { r: 75, g: 0, b: 130 }),
+    ("VIOLET", RGB8 { r: 238, g: 130, b: 238 }),
+    ("CORAL", RGB8 { r: 255, g: 127, b: 80 }),
+    ("SALMON", RGB8 { r: 250, g: 128, b: 114 }),
+    ("TOMATO", RGB8 { r: 255, g: 99, b: 71 }),
+    ("CRIMSON", RGB8 { r: 220, g: 20, b: 60 }),
+    ("TURQUOISE", RGB8 { r: 64, g: 224, b: 208 }),
+    ("SKYBLUE", RGB8 { r: 135, g: 206, b: 235 }),
+    ("ROYALBLUE", RGB8 { r: 65, g: 105, b: 225 }),
+    ("KHAKI", RGB8 { r: 240, g: 230, b: 140 }),
+    ("LAVENDER", RGB8 { r: 230, g: 230, b: 250 }),
+    ("CHOCOLATE", RGB8 { r: 210, g: 105, b: 30 }),
+    ("CHARTREUSE", RGB8 { r: 127, g: 255, b: 0 }),
+    ("SPRINGGREEN", RGB8 { r: 0, g: 255, b: 127 }),
+    ("DEEPPINK", RGB8 { r: 255, g: 20, b: 147 }),
+    ("WHEAT", RGB8 { r: 245, g: 222, b: 179 }),
+    ("AMBER", RGB8 { r: 255, g: 191, b: 0 }),
+];
+
+/// Color given by hue in degrees (0 - 360), saturation and value (0.0 - 1.0).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hsv {
+    pub hue: f32,
+    pub saturation: f32,
+    pub value: f32,
+}
+
+/// Color given by hue in degrees (0 - 360), saturation and lightness (0.0 - 1.0).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hsl {
+    pub hue: f32,
+    pub saturation: f32,
+    pub lightness: f32,
+}
+
+fn to_byte(value: f32) -> u8 {
+    roundf(value.clamp(0.0, 1.0) * 255.0) as u8
+}
+
+fn normalize_hue(hue: f32) -> f32 {
+    let hue = fmodf(hue, 360.0);
+    if hue < 0.0 { hue + 360.0 } else { hue }
+}
+
+/// Color of the hue with the given chroma, shifted by `lowest` in all the channels.
+fn from_chroma(hue: f32, chroma: f32, lowest: f32) -> RGB8 {
+    let sector = normalize_hue(hue) / 60.0;
+    let x = chroma * (1.0 - fabsf(fmodf(sector, 2.0) - 1.0));
+
+    let (r, g, b) = match sector as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+
+    RGB8 { r: to_byte(r + lowest), g: to_byte(g + lowest), b: to_byte(b + lowest) }
+}
+
+/// Hue, chroma and the highest and lowest channel of the color, all but hue in 0.0 - 1.0.
+fn to_chroma(rgb: RGB8) -> (f32, f32, f32, f32) {
+    let (r, g, b) = (rgb.r as f32 / 255.0, rgb.g as f32 / 255.0, rgb.b as f32 / 255.0);
+    let highest = r.max(g).max(b);
+    let lowest = r.min(g).min(b);
+    let chroma = highest - lowest;
+
+    let hue = if chroma == 0.0 {
+        0.0
+    } else if highest == r {
+        60.0 * fmodf((g - b) / chroma, 6.0)
+    } else if highest == g {
+        60.0 * ((b - r) / chroma + 2.0)
+    } else {
+        60.0 * ((r - g) / chroma + 4.0)
+    };
+
+    (normalize_hue(hue), chroma, highest, lowest)
+}
+
+impl Hsv {
+    pub fn new(hue: f32, saturation: f32, value: f32) -> Self {
+        Self {
+            hue,
+            saturation,
+            value
+        }
+    }
+
+    pub fn from_rgb(rgb: RGB8) -> Self {
+        let (hue, chroma, highest, _) = to_chroma(rgb);
+        let saturation = if highest == 0.0 { 0.0 } else { chroma / highest };
+
+        Self::new(hue, saturation, highest)
+    }
+
+    pub fn to_rgb(&self) -> RGB8 {
+        let value = self.value.clamp(0.0, 1.0);
+        let chroma = value * self.saturation.clamp(0.0, 1.0);
+
+        from_chroma(self.hue, chroma, value - chroma)
+    }
+}
+
+impl Hsl {
+    pub fn new(hue: f32, saturation: f32, lightness: f32) -> Self {
+        Self {
+            hue,
+            saturation,
+            lightness
+        }
+    }
+
+    pub fn from_rgb(rgb: RGB8) -> Self {
+        let (hue, chroma, highest, lowest) = to_chroma(rgb);
+        let lightness = (highest + lowest) / 2.0;
+        let saturation = if lightness == 0.0 || lightness == 1.0 {
+            0.0
+        } else {
+            chroma / (1.0 - fabsf(2.0 * lightness - 1.0))
+        };
+
+        Self::new(hue, saturation, lightness)
+    }
+
+    pub fn to_rgb(&self) -> RGB8 {
+        let lightness = self.lightness.clamp(0.0, 1.0);
+        let chroma = (1.0 - fabsf(2.0 * lightness - 1.0)) * self.saturation.clamp(0.0, 1.0);
+
+        from_chroma(self.hue, chroma, lightness - chroma / 2.0)
+    }
+}
+
+/// Color of a black body at the temperature, approximation by Tanner Helland.
+/// Temperatures out of `MIN_KELVIN` - `MAX_KELVIN` are clamped.
+pub fn kelvin(temperature: u32) -> RGB8 {
+    let temperature = temperature.clamp(MIN_KELVIN, MAX_KELVIN) as f32 / 100.0;
+
+    let r = if temperature <= 66.0 {
+        255.0
+    } else {
+        329.69873 * powf(temperature - 60.0, -0.13320476)
+    };
+
+    let g = if temperature <= 66.0 {
+        99.4708 * logf(temperature) - 161.11957
+    } else {
+        288.12216 * powf(temperature - 60.0, -0.075514846)
+    };
+
+    let b = if temperature >= 66.0 {
+        255.0
+    } else if temperature <= 19.0 {
+        0.0
+    } else {
+        138.51773 * logf(temperature - 10.0) - 305.0448
+    };
+
+    RGB8 { r: to_byte(r / 255.0), g: to_byte(g / 255.0), b: to_byte(b / 255.0) }
+}
+
+#[cfg(test)]
+mod tests {
+    use smart_leds::RGB8;
+    use super::{kelvin, Hsl, Hsv, MAX_KELVIN, MIN_KELVIN};
+
+    const RED: RGB8 = RGB8 { r: 255, g: 0, b: 0 };
+    const GREEN: RGB8 = RGB8 { r: 0, g: 255, b: 0 };
+    const BLUE: RGB8 = RGB8 { r: 0, g: 0, b: 255 };
+    const GREY: RGB8 = RGB8 { r: 128, g: 128, b: 128 };
+
+    #[test]
+    fn converts_hsv_to_rgb() {
+        assert_eq!(Hsv::new(0.0, 1.0, 1.0).to_rgb(), RED);
+        assert_eq!(Hsv::new(120.0, 1.0, 1.0).to_rgb(), GREEN);
+        assert_eq!(Hsv::new(240.0, 1.0, 1.0).to_rgb(), BLUE);
+        assert_eq!(Hsv::new(360.0, 1.0, 1.0).to_rgb(), RED);
+        assert_eq!(Hsv::new(-120.0, 1.0, 1.0).to_rgb(), BLUE);
+        assert_eq!(Hsv::new(60.0, 1.0, 1.0).to_rgb(), RGB8 { r: 255, g: 255, b: 0 });
+        assert_eq!(Hsv::new(200.0, 0.0, 128.0 / 255.0).to_rgb(), GREY);
+        assert_eq!(Hsv::new(0.0, 1.0, 0.0).to_rgb(), RGB8::default());
+    }
+
+    #[test]
+    fn converts_hsl_to_rgb() {
+        assert_eq!(Hsl::new(0.0, 1.0, 0.5).to_rgb(), RED);
+        assert_eq!(Hsl::new(120.0, 1.0, 0.5).to_rgb(), GREEN);
+        assert_eq!(Hsl::new(240.0, 1.0, 0.5).to_rgb(), BLUE);
+        assert_eq!(Hsl::new(0.0, 1.0, 1.0).to_rgb(), RGB8 { r: 255, g: 255, b: 255 });
+        assert_eq!(Hsl::new(0.0, 1.0, 0.0).to_rgb(), RGB8::default());
+        assert_eq!(Hsl::new(90.0, 0.0, 128.0 / 255.0).to_rgb(), GREY);
+    }
+
+    #[test]
+    fn converts_rgb_to_hsv_and_hsl() {
+        assert_eq!(Hsv::from_rgb(BLUE), Hsv::new(240.0, 1.0, 1.0));
+        assert_eq!(Hsv::from_rgb(GREY).saturation, 0.0);
+        assert_eq!(Hsl::from_rgb(GREEN), Hsl::new(120.0, 1.0, 0.5));
+        assert_eq!(Hsl::from_rgb(RGB8::default()).saturation, 0.0);
+    }
+
+    #[test]
+    fn round_trips_through_hsv_and_hsl() {
+        for r in (0..=255).step_by(15) {
+            for g in (0..=255).step_by(15) {
+                for b in (0..=255).step_by(15) {
+                    let rgb = RGB8 { r, g, b };
+                    assert_eq!(Hsv::from_rgb(rgb).to_rgb(), rgb);
+                    assert_eq!(Hsl::from_rgb(rgb).to_rgb(), rgb);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn converts_temperatures() {
+        // daylight is about white
+        let daylight = kelvin(6500);
+        assert_eq!(daylight.r, 255);
+        assert!(daylight.g >= 245 && daylight.b >= 245);
+
+        // candle light has no blue, hot stars are bluish
+        let candle = kelvin(MIN_KELVIN);
+        assert_eq!((candle.r, candle.b), (255, 0));
+        let star = kelvin(MAX_KELVIN);
+        assert!(star.b == 255 && star.r < star.b);
+
+        assert_eq!(kelvin(0), kelvin(MIN_KELVIN));
+        assert_eq!(kelvin(u32::MAX), kelvin(MAX_KELVIN));
+    }
+}
@@ -0,0 +1,104 @@
+use alloc::vec::Vec;
+use smart_leds::RGB8;
+
+/// Color at the given position (0.0 - 1.0) of a colormap.
+pub type Stop = (f32, RGB8);
+
+const VIRIDIS: [Stop; 9] = [
+    (0.0, RGB8 { r: 68, g: 1, b: 84 }),
+    (0.125, RGB8 { r: 71, g: 44, b: 122 }),
+    (0.25, RGB8 { r: 59, g: 81, b: 139 }),
+    (0.375, RGB8 { r: 44, g: 113, b: 142 }),
+    (0.5, RGB8 { r: 33, g: 144, b: 141 }),
+    (0.625, RGB8 { r: 39, g: 173, b: 129 }),
+    (0.75, RGB8 { r: 92, g: 200, b: 99 }),
+    (0.875, RGB8 { r: 170, g: 220, b: 50 }),
+    (1.0, RGB8 { r: 253, g: 231, b: 37 }),
+];
+
+const MAGMA: [Stop; 9] = [
+    (0.0, RGB8 { r: 0, g: 0, b: 4 }),
+    (0.125, RGB8 { r: 28, g: 16, b: 68 }),
+    (0.25, RGB8 { r: 79, g: 18, b: 123 }),
+    (0.375, RGB8 { r: 129, g: 37, b: 129 }),
+    (0.5, RGB8 { r: 181, g: 54, b: 122 }),
+    (0.625, RGB8 { r: 229, g: 80, b: 100 }),
+    (0.75, RGB8 { r: 251, g: 135, b: 97 }),
+    (0.875, RGB8 { r: 254, g: 194, b: 135 }),
+    (1.0, RGB8 { r: 252, g: 253, b: 191 }),
+];
+
+/// Blue for the low values, white in the middle and red for the high ones.
+const RED_BLUE: [Stop; 5] = [
+    (0.0, RGB8 { r: 33, g: 102, b: 172 }),
+    (0.25, RGB8 { r: 103, g: 169, b: 207 }),
+    (0.5, RGB8 { r: 247, g: 247, b: 247 }),
+    (0.75, RGB8 { r: 239, g: 138, b: 98 }),
+    (1.0, RGB8 { r: 178, g: 24, b: 43 }),
+];
+
+/// Maps values normalized to 0.0 - 1.0 to colors.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Colormap {
+    Viridis,
+    Magma,
+    /// Diverging, blue - white - red.
+    RedBlue,
+    /// Sequential from off to the given color.
+    Hue(RGB8),
+    /// Colors at the given positions, sorted by the position.
+    Custom(Vec<Stop>),
+}
+
+impl Colormap {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Colormap::Viridis => "VIRIDIS",
+            Colormap::Magma => "MAGMA",
+            Colormap::RedBlue => "REDBLUE",
+            Colormap::Hue(_) => "HUE",
+            Colormap::Custom(_) => "CUSTOM",
+        }
+    }
+
+    pub fn color(&self, position: f32) -> RGB8 {
+        match self {
+            Colormap::Viridis => interpolate(&VIRIDIS, position),
+            Colormap::Magma => interpolate(&MAGMA, position),
+            Colormap::RedBlue => interpolate(&RED_BLUE, position),
+            Colormap::Hue(color) => interpolate(&[(0.0, RGB8 { r: 0, g: 0, b: 0 }), (1.0, *color)], position),
+            Colormap::Custom(stops) => interpolate(stops, position),
+        }
+    }
+}
+
+/// Linear interpolation between the two stops around the position,
+/// positions outside of the stops get the color of the nearest one.
+fn interpolate(stops: &[Stop], position: f32) -> RGB8 {
+    let (first, last) = match (stops.first(), stops.last()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return RGB8 { r: 0, g: 0, b: 0 }
+    };
+
+    if position <= first.0 {
+        return first.1;
+    }
+
+    for window in stops.windows(2) {
+        let ((from, low), (to, high)) = (window[0], window[1]);
+        if position <= to {
+            let t = if to > from { (position - from) / (to - from) } else { 1.0 };
+            return RGB8 {
+                r: mix(low.r, high.r, t),
+                g: mix(low.g, high.g, t),
+                b: mix(low.b, high.b, t),
+            };
+        }
+    }
+
+    last.1
+}
+
+fn mix(low: u8, high: u8, t: f32) -> u8 {
+    (low as f32 + (high as f32 - low as f32) * t + 0.5) as u8
+}
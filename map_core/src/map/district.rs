@@ -0,0 +1,135 @@
+use libm::{asinf, cosf, sinf, sqrtf};
+
+/// Mean radius of the Earth in kilometers.
+pub const EARTH_RADIUS: f32 = 6371.0;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Coordinates {
+    pub latitude: f32,
+    pub longitude: f32,
+}
+
+impl Coordinates {
+    pub const fn new(latitude: f32, longitude: f32) -> Self {
+        Self {
+            latitude,
+            longitude
+        }
+    }
+
+    /// Great-circle distance to the other coordinates in kilometers.
+    pub fn distance(&self, other: Coordinates) -> f32 {
+        let latitude = self.latitude.to_radians();
+        let other_latitude = other.latitude.to_radians();
+        let half_latitude = (other_latitude - latitude) / 2.0;
+        let half_longitude = (other.longitude - self.longitude).to_radians() / 2.0;
+
+        let a = sinf(half_latitude) * sinf(half_latitude)
+            + cosf(latitude) * cosf(other_latitude) * sinf(half_longitude) * sinf(half_longitude);
+        2.0 * EARTH_RADIUS * asinf(sqrtf(a.min(1.0)))
+    }
+}
+
+use crate::map::name::Named;
+
+/// District lit by one LED of the board.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct District {
+    pub name: &'static str,
+    /// Other names the district may be addressed by, e.g. English names,
+    /// licence plate codes or LAU codes.
+    pub aliases: &'static [&'static str],
+    /// Index of the region the district belongs to.
+    pub region: usize,
+    /// Approximate centre of the district, where its LED is on the board.
+    pub coordinates: Coordinates,
+}
+
+impl District {
+    pub const fn new(name: &'static str, region: usize, latitude: f32, longitude: f32) -> Self {
+        Self {
+            name,
+            aliases: &[],
+            region,
+            coordinates: Coordinates::new(latitude, longitude)
+        }
+    }
+
+    pub const fn with_aliases(self, aliases: &'static [&'static str]) -> Self {
+        Self {
+            aliases,
+            ..self
+        }
+    }
+}
+
+/// Bounding box of all the districts of a board.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bounds {
+    pub north: f32,
+    pub south: f32,
+    pub west: f32,
+    pub east: f32,
+}
+
+impl Bounds {
+    pub fn of(districts: &[District]) -> Self {
+        let mut bounds = Bounds {
+            north: f32::MIN,
+            south: f32::MAX,
+            west: f32::MAX,
+            east: f32::MIN,
+        };
+
+        for district in districts {
+            let coordinates = district.coordinates;
+            bounds.north = bounds.north.max(coordinates.latitude);
+            bounds.south = bounds.south.min(coordinates.latitude);
+            bounds.west = bounds.west.min(coordinates.longitude);
+            bounds.east = bounds.east.max(coordinates.longitude);
+        }
+
+        bounds
+    }
+
+    /// Position of the coordinates on the board, x goes from 0.0 on the west
+    /// to 1.0 on the east, y from 0.0 on the north to 1.0 on the south.
+    pub fn normalize(&self, coordinates: Coordinates) -> (f32, f32) {
+        let width = self.east - self.west;
+        let height = self.north - self.south;
+
+        let x = if width > 0.0 { (coordinates.longitude - self.west) / width } else { 0.0 };
+        let y = if height > 0.0 { (self.north - coordinates.latitude) / height } else { 0.0 };
+
+        (x, y)
+    }
+}
+
+impl Named for District {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn aliases(&self) -> &[&str] {
+        self.aliases
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Coordinates;
+
+    const PRAHA: Coordinates = Coordinates::new(50.08, 14.42);
+    const BRNO: Coordinates = Coordinates::new(49.20, 16.61);
+
+    #[test]
+    fn measures_great_circle_distance() {
+        assert!((PRAHA.distance(BRNO) - 185.6).abs() < 0.5);
+        assert_eq!(PRAHA.distance(BRNO), BRNO.distance(PRAHA));
+        assert_eq!(PRAHA.distance(PRAHA), 0.0);
+        // a degree of latitude is about 111 km anywhere
+        assert!((Coordinates::new(49.0, 15.0).distance(Coordinates::new(50.0, 15.0)) - 111.2).abs() < 0.5);
+        // half of the circumference between antipodes
+        assert!((Coordinates::new(0.0, 0.0).distance(Coordinates::new(0.0, 180.0)) - 20015.1).abs() < 5.0);
+    }
+}
@@ -0,0 +1,134 @@
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use smart_leds::RGB8;
+use crate::map::choropleth::Choropleth;
+
+/// Most changes that can be undone.
+pub const MAX_HISTORY: usize = 20;
+
+/// State of the map changed by the commands, the base colors and the values.
+#[derive(Clone, PartialEq)]
+pub struct Snapshot {
+    pub colors: Vec<RGB8>,
+    pub choropleth: Choropleth,
+}
+
+/// States of the map before the recent changes, and the undone ones.
+#[derive(Default)]
+pub struct History {
+    undo: VecDeque<Snapshot>,
+    redo: Vec<Snapshot>,
+}
+
+impl History {
+    pub fn new() -> Self {
+        Self {
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+        }
+    }
+
+    /// Remembers the state before a change, the undone changes cannot be redone anymore.
+    pub fn push(&mut self, before: Snapshot) {
+        if self.undo.len() >= MAX_HISTORY {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(before);
+        self.redo.clear();
+    }
+
+    /// State to go back to, `current` can be returned to by `redo`.
+    pub fn undo(&mut self, current: Snapshot) -> Option<Snapshot> {
+        let previous = self.undo.pop_back()?;
+        self.redo.push(current);
+        Some(previous)
+    }
+
+    pub fn redo(&mut self, current: Snapshot) -> Option<Snapshot> {
+        let next = self.redo.pop()?;
+        self.undo.push_back(current);
+        Some(next)
+    }
+
+    pub fn undo_count(&self) -> usize {
+        self.undo.len()
+    }
+
+    pub fn redo_count(&self) -> usize {
+        self.redo.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use smart_leds::RGB8;
+    use crate::map::choropleth::Choropleth;
+    use super::{History, Snapshot, MAX_HISTORY};
+
+    fn snapshot(step: usize) -> Snapshot {
+        Snapshot {
+            colors: vec![RGB8 { r: step as u8, g: 0, b: 0 }],
+            choropleth: Choropleth::new(1),
+        }
+    }
+
+    fn step(snapshot: &Snapshot) -> usize {
+        snapshot.colors[0].r as usize
+    }
+
+    #[test]
+    fn undoes_and_redoes_changes() {
+        let mut history = History::new();
+        history.push(snapshot(0));
+        history.push(snapshot(1));
+
+        let previous = history.undo(snapshot(2)).unwrap();
+        assert_eq!(step(&previous), 1);
+        let previous = history.undo(previous).unwrap();
+        assert_eq!(step(&previous), 0);
+        assert!(history.undo(snapshot(0)).is_none());
+
+        let next = history.redo(previous).unwrap();
+        assert_eq!(step(&next), 1);
+        let next = history.redo(next).unwrap();
+        assert_eq!(step(&next), 2);
+        assert!(history.redo(snapshot(2)).is_none());
+        assert_eq!((history.undo_count(), history.redo_count()), (2, 0));
+    }
+
+    #[test]
+    fn new_change_drops_the_undone_ones() {
+        let mut history = History::new();
+        history.push(snapshot(0));
+        history.push(snapshot(1));
+        history.undo(snapshot(2)).unwrap();
+
+        history.push(snapshot(1));
+        assert_eq!(history.redo_count(), 0);
+        assert!(history.redo(snapshot(3)).is_none());
+    }
+
+    #[test]
+    fn keeps_at_most_max_history_changes() {
+        let mut history = History::new();
+        for i in 0..MAX_HISTORY + 5 {
+            history.push(snapshot(i));
+        }
+        assert_eq!(history.undo_count(), MAX_HISTORY);
+
+        let mut current = snapshot(MAX_HISTORY + 5);
+        for _ in 0..MAX_HISTORY {
+            current = history.undo(current).unwrap();
+        }
+        // the oldest changes were forgotten
+        assert_eq!(step(&current), 5);
+        assert!(history.undo(snapshot(5)).is_none());
+
+        for _ in 0..MAX_HISTORY {
+            current = history.redo(current).unwrap();
+        }
+        assert_eq!(step(&current), MAX_HISTORY + 5);
+        assert_eq!((history.undo_count(), history.redo_count()), (MAX_HISTORY, 0));
+    }
+}
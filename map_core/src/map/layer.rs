@@ -0,0 +1,134 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use smart_leds::RGB8;
+
+/// How a layer is combined with the layers below it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BlendMode {
+    /// The color of the layer is used as is.
+    Replace,
+    /// The colors are added, saturating at 255.
+    Add,
+    /// The colors are multiplied, white keeps the color below.
+    Multiply,
+    /// The brighter of the two is used for each channel.
+    Max,
+    /// The color of the layer is mixed with the color below by the opacity of the layer.
+    Alpha,
+}
+
+impl BlendMode {
+    pub const ALL: [BlendMode; 5] = [BlendMode::Replace, BlendMode::Add, BlendMode::Multiply, BlendMode::Max, BlendMode::Alpha];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            BlendMode::Replace => "REPLACE",
+            BlendMode::Add => "ADD",
+            BlendMode::Multiply => "MULTIPLY",
+            BlendMode::Max => "MAX",
+            BlendMode::Alpha => "ALPHA",
+        }
+    }
+
+    pub fn blend(&self, below: RGB8, above: RGB8, opacity: u8) -> RGB8 {
+        match self {
+            BlendMode::Replace => above,
+            BlendMode::Add => RGB8 {
+                r: below.r.saturating_add(above.r),
+                g: below.g.saturating_add(above.g),
+                b: below.b.saturating_add(above.b),
+            },
+            BlendMode::Multiply => RGB8 {
+                r: multiply(below.r, above.r),
+                g: multiply(below.g, above.g),
+                b: multiply(below.b, above.b),
+            },
+            BlendMode::Max => RGB8 {
+                r: below.r.max(above.r),
+                g: below.g.max(above.g),
+                b: below.b.max(above.b),
+            },
+            BlendMode::Alpha => RGB8 {
+                r: mix(below.r, above.r, opacity),
+                g: mix(below.g, above.g, opacity),
+                b: mix(below.b, above.b, opacity),
+            },
+        }
+    }
+}
+
+fn multiply(a: u8, b: u8) -> u8 {
+    ((a as u16 * b as u16 + 127) / 255) as u8
+}
+
+fn mix(below: u8, above: u8, opacity: u8) -> u8 {
+    ((below as u16 * (255 - opacity) as u16 + above as u16 * opacity as u16 + 127) / 255) as u8
+}
+
+/// Colors of the LEDs drawn over the base colors of the map.
+///
+/// Only the LEDs drawn since the layer was cleared are opaque, the others
+/// leave the colors below untouched. Black is drawn like any other color.
+pub struct Layer {
+    data: Vec<RGB8>,
+    opaque: Vec<bool>,
+    blend_mode: BlendMode,
+    opacity: u8,
+}
+
+impl Layer {
+    pub fn new(leds_count: usize, blend_mode: BlendMode) -> Self {
+        Self {
+            data: vec![RGB8 { r: 0, g: 0, b: 0 }; leds_count],
+            opaque: vec![false; leds_count],
+            blend_mode,
+            opacity: 255,
+        }
+    }
+
+    pub fn blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
+    pub fn set_blend_mode(&mut self, blend_mode: BlendMode) {
+        self.blend_mode = blend_mode;
+    }
+
+    /// Opacity used by the `Alpha` blend mode.
+    pub fn opacity(&self) -> u8 {
+        self.opacity
+    }
+
+    pub fn set_opacity(&mut self, opacity: u8) {
+        self.opacity = opacity;
+    }
+
+    pub fn data(&self) -> &[RGB8] {
+        &self.data
+    }
+
+    /// Whether the LED was drawn to since the layer was cleared.
+    pub fn is_opaque(&self, index: usize) -> bool {
+        self.opaque.get(index).copied().unwrap_or(false)
+    }
+
+    /// Colors of the LEDs together with the mask of the opaque ones,
+    /// whoever changes a color has to mark the LED opaque.
+    pub fn data_mut(&mut self) -> (&mut [RGB8], &mut [bool]) {
+        (&mut self.data, &mut self.opaque)
+    }
+
+    /// Makes all the LEDs transparent.
+    pub fn clear(&mut self) {
+        self.data.fill(RGB8 { r: 0, g: 0, b: 0 });
+        self.opaque.fill(false);
+    }
+
+    /// Color of the LED after blending the layer over the given color.
+    pub fn blend(&self, index: usize, below: RGB8) -> RGB8 {
+        match self.data.get(index) {
+            Some(&above) if self.is_opaque(index) => self.blend_mode.blend(below, above, self.opacity),
+            _ => below,
+        }
+    }
+}
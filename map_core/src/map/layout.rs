@@ -0,0 +1,27 @@
+use crate::map::district::District;
+use crate::map::region::Region;
+
+/// Description of a board, its districts in the order of the LEDs
+/// on the strip, their regions and borders.
+///
+/// The layout the crate is built for is compiled in from a layout file
+/// selected by the `MAP_LAYOUT` environment variable, see `layouts/czech.layout`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Layout {
+    pub name: &'static str,
+    /// Regions grouping the districts, districts refer to them by index.
+    pub regions: &'static [Region],
+    pub districts: &'static [District],
+    /// Pairs of districts sharing a border, each pair is listed once.
+    pub borders: &'static [(usize, usize)],
+    /// Order of the districts the snake goes through.
+    pub snake: &'static [usize],
+}
+
+impl Layout {
+    pub const fn leds_count(&self) -> usize {
+        self.districts.len()
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/layout.rs"));
@@ -0,0 +1,164 @@
+use alloc::vec::Vec;
+
+/// Anything that can be looked up by its name or one of its aliases.
+pub trait Named {
+    fn name(&self) -> &str;
+    fn aliases(&self) -> &[&str];
+}
+
+/// Folds the character for name comparison, letters are uppercased
+/// and stripped of Czech diacritics, spaces and dashes become underscores.
+pub fn fold(c: char) -> char {
+    match c {
+        'Á' | 'á' | 'Ä' | 'ä' => 'A',
+        'Č' | 'č' => 'C',
+        'Ď' | 'ď' => 'D',
+        'É' | 'é' | 'Ě' | 'ě' => 'E',
+        'Í' | 'í' => 'I',
+        'Ň' | 'ň' => 'N',
+        'Ó' | 'ó' | 'Ö' | 'ö' => 'O',
+        'Ř' | 'ř' => 'R',
+        'Š' | 'š' => 'S',
+        'Ť' | 'ť' => 'T',
+        'Ú' | 'ú' | 'Ů' | 'ů' | 'Ü' | 'ü' => 'U',
+        'Ý' | 'ý' => 'Y',
+        'Ž' | 'ž' => 'Z',
+        ' ' | '-' => '_',
+        c => c.to_ascii_uppercase()
+    }
+}
+
+/// Whether the names are the same after folding.
+pub fn matches(current: &str, name: &[char]) -> bool {
+    current.chars().map(fold).eq(name.iter().map(|c| fold(*c)))
+}
+
+/// Whether the folded name starts with the folded prefix.
+pub fn starts_with(current: &str, prefix: &[char]) -> bool {
+    let mut current = current.chars().map(fold);
+    prefix.iter().all(|c| current.next() == Some(fold(*c)))
+}
+
+/// Whether the folded name matches the folded pattern, `*` in the pattern stands for any characters.
+pub fn wildcard_matches(current: &str, pattern: &[char]) -> bool {
+    let current: Vec<char> = current.chars().map(fold).collect();
+    let pattern: Vec<char> = pattern.iter().map(|c| fold(*c)).collect();
+
+    // position after the last star and the position in the name it was tried at
+    let mut star: Option<(usize, usize)> = None;
+    let (mut i, mut j) = (0, 0);
+
+    while i < current.len() {
+        if j < pattern.len() && pattern[j] == '*' {
+            star = Some((j + 1, i));
+            j += 1;
+        } else if j < pattern.len() && pattern[j] == current[i] {
+            i += 1;
+            j += 1;
+        } else if let Some((after_star, tried)) = star {
+            // let the star take one more character
+            star = Some((after_star, tried + 1));
+            i = tried + 1;
+            j = after_star;
+        } else {
+            return false;
+        }
+    }
+
+    pattern[j..].iter().all(|c| *c == '*')
+}
+
+/// Levenshtein distance of the folded names.
+pub fn edit_distance(current: &str, name: &[char]) -> usize {
+    let current: Vec<char> = current.chars().map(fold).collect();
+    let mut previous: Vec<usize> = (0..=current.len()).collect();
+    let mut row = Vec::with_capacity(current.len() + 1);
+
+    for (i, c) in name.iter().map(|c| fold(*c)).enumerate() {
+        row.clear();
+        row.push(i + 1);
+        for (j, other) in current.iter().enumerate() {
+            let substitution = previous[j] + if c == *other { 0 } else { 1 };
+            row.push(substitution.min(previous[j + 1] + 1).min(row[j] + 1));
+        }
+        core::mem::swap(&mut previous, &mut row);
+    }
+
+    previous[current.len()]
+}
+
+/// How much the name differs from the current one, the lower the closer,
+/// `None` if they are too different to be suggested.
+/// The name is also compared to the beginning of the current one
+/// so that mistyped prefixes get suggested as well.
+pub fn similarity(current: &str, name: &[char]) -> Option<usize> {
+    if name.is_empty() {
+        return None;
+    }
+
+    if starts_with(current, name) {
+        return Some(0);
+    }
+
+    let prefix_length = current.char_indices()
+        .nth(name.len())
+        .map(|(i, _)| i)
+        .unwrap_or(current.len());
+
+    let distance = edit_distance(current, name)
+        .min(edit_distance(&current[..prefix_length], name));
+
+    if distance <= (name.len() / 4).max(1) {
+        Some(distance)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use super::{edit_distance, fold, matches, similarity, starts_with};
+
+    fn chars(name: &str) -> Vec<char> {
+        name.chars().collect()
+    }
+
+    #[test]
+    fn folds_case_and_diacritics() {
+        assert_eq!(fold('ř'), 'R');
+        assert_eq!(fold('Ů'), 'U');
+        assert_eq!(fold('x'), 'X');
+        assert_eq!(fold(' '), '_');
+        assert_eq!(fold('-'), '_');
+        assert!(matches("ÚSTI_NAD_LABEM", &chars("usti nad labem")));
+        assert!(matches("ZĎAR_NAD_SAZAVOU", &chars("Žďár-nad-Sázavou")));
+        assert!(!matches("BRNO", &chars("BRN")));
+    }
+
+    #[test]
+    fn compares_beginnings() {
+        assert!(starts_with("HRADEC_KRALOVE", &chars("hradec k")));
+        assert!(starts_with("BRNO", &chars("")));
+        assert!(!starts_with("BRNO", &chars("BRNOX")));
+        assert!(!starts_with("BRNO", &chars("RNO")));
+    }
+
+    #[test]
+    fn measures_edit_distance() {
+        assert_eq!(edit_distance("BRNO", &chars("brno")), 0);
+        assert_eq!(edit_distance("BRNO", &chars("BRANO")), 1);
+        assert_eq!(edit_distance("BRNO", &chars("BNRO")), 2);
+        assert_eq!(edit_distance("BRNO", &chars("")), 4);
+        assert_eq!(edit_distance("", &chars("BRNO")), 4);
+    }
+
+    #[test]
+    fn suggests_only_similar_names() {
+        assert_eq!(similarity("OLOMOUC", &chars("OLOM")), Some(0));
+        assert_eq!(similarity("OLOMOUC", &chars("OLOMUC")), Some(1));
+        assert_eq!(similarity("PARDUBICE", &chars("PARDUB1")), Some(1));
+        assert_eq!(similarity("OLOMOUC", &chars("PRAHA")), None);
+        assert_eq!(similarity("OLOMOUC", &chars("")), None);
+    }
+}
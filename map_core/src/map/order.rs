@@ -0,0 +1,190 @@
+use alloc::vec::Vec;
+use core::f32::consts::PI;
+use libm::{atan2f, floorf, sqrtf};
+use crate::map::{Error, Map};
+
+/// Distance between the turns of the spiral, in the normalized positions of the board.
+const SPIRAL_PITCH: f32 = 0.1;
+
+/// Order to go through all the districts in, e.g. by the snake.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Order {
+    /// Path of the layout going through the neighbouring districts.
+    Snake,
+    WestEast,
+    NorthSouth,
+    /// Spiral going out of the centre of the board.
+    Spiral,
+    /// By the distance from the centre of the district.
+    Distance(usize),
+    /// By the number of borders to cross from the district.
+    Breadth(usize),
+    /// Random permutation given by the seed.
+    Random(u32),
+}
+
+impl Order {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Order::Snake => "SNAKE",
+            Order::WestEast => "WEST",
+            Order::NorthSouth => "NORTH",
+            Order::Spiral => "SPIRAL",
+            Order::Distance(_) => "FROM",
+            Order::Breadth(_) => "BFS",
+            Order::Random(_) => "RANDOM",
+        }
+    }
+
+    /// Indices of all the districts of the map in this order.
+    pub fn indices(&self, map: &Map) -> Result<Vec<usize>, Error> {
+        let count = map.districts().len();
+        let mut indices: Vec<usize> = (0..count).collect();
+
+        match *self {
+            Order::Snake => {
+                let snake = map.layout().snake;
+                if snake.len() == count {
+                    indices.copy_from_slice(snake);
+                }
+            }
+            Order::WestEast => {
+                Self::sort_by_key(&mut indices, |index| map.coordinates(index).map(|c| c.longitude).unwrap_or(0.0));
+            }
+            Order::NorthSouth => {
+                Self::sort_by_key(&mut indices, |index| -map.coordinates(index).map(|c| c.latitude).unwrap_or(0.0));
+            }
+            Order::Spiral => {
+                Self::sort_by_key(&mut indices, |index| Self::spiral_key(map.position(index).unwrap_or((0.5, 0.5))));
+            }
+            Order::Distance(from) => {
+                let center = map.coordinates(from)?;
+                Self::sort_by_key(&mut indices, |index| map.coordinates(index).map(|c| c.distance(center)).unwrap_or(0.0));
+            }
+            Order::Breadth(from) => {
+                let center = map.coordinates(from)?;
+                let hops = map.distances(from)?;
+                // unreachable districts go last, the nearer ones first within the same hop
+                indices.sort_by(|a, b| {
+                    let a_hops = hops[*a].unwrap_or(usize::MAX);
+                    let b_hops = hops[*b].unwrap_or(usize::MAX);
+                    let a_distance = map.coordinates(*a).map(|c| c.distance(center)).unwrap_or(0.0);
+                    let b_distance = map.coordinates(*b).map(|c| c.distance(center)).unwrap_or(0.0);
+                    a_hops.cmp(&b_hops).then(a_distance.total_cmp(&b_distance))
+                });
+            }
+            Order::Random(seed) => {
+                // xorshift, zero would stay zero forever
+                let mut state = if seed == 0 { 0x9E37_79B9 } else { seed };
+                for i in (1..count).rev() {
+                    state ^= state << 13;
+                    state ^= state >> 17;
+                    state ^= state << 5;
+                    indices.swap(i, state as usize % (i + 1));
+                }
+            }
+        }
+
+        Ok(indices)
+    }
+
+    fn sort_by_key<F: Fn(usize) -> f32>(indices: &mut [usize], key: F) {
+        indices.sort_by(|a, b| key(*a).total_cmp(&key(*b)));
+    }
+
+    /// Position along the spiral, every point belongs to the nearest turn inside of it.
+    fn spiral_key((x, y): (f32, f32)) -> f32 {
+        let (dx, dy) = (x - 0.5, y - 0.5);
+        let radius = sqrtf(dx * dx + dy * dy) / SPIRAL_PITCH;
+        let angle = (atan2f(dy, dx) + PI) / (2.0 * PI);
+
+        floorf(radius - angle) + angle
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use smart_leds::RGB8;
+    use crate::map::layout::LAYOUT;
+    use crate::map::Map;
+    use super::Order;
+
+    /// Indices in the order, checking that each district is there exactly once.
+    fn indices(order: Order) -> Vec<usize> {
+        let mut data = vec![RGB8::default(); LAYOUT.leds_count()];
+        let map = Map::new(&LAYOUT, &mut data);
+        let indices = map.order(order).ok().unwrap();
+
+        let mut sorted = indices.clone();
+        sorted.sort();
+        assert_eq!(sorted, (0..LAYOUT.leds_count()).collect::<Vec<usize>>(), "{} is not a permutation", order.name());
+        indices
+    }
+
+    fn is_sorted_by_key<F: Fn(usize) -> f32>(indices: &[usize], key: F) -> bool {
+        indices.windows(2).all(|pair| key(pair[0]) <= key(pair[1]))
+    }
+
+    #[test]
+    fn all_orders_go_through_every_district() {
+        for order in [Order::Snake, Order::WestEast, Order::NorthSouth, Order::Spiral, Order::Distance(5), Order::Breadth(5), Order::Random(0), Order::Random(42)] {
+            indices(order);
+        }
+    }
+
+    #[test]
+    fn snake_follows_the_layout() {
+        assert_eq!(indices(Order::Snake), LAYOUT.snake);
+    }
+
+    #[test]
+    fn sorts_by_coordinates() {
+        let coordinates = |index: usize| LAYOUT.districts[index].coordinates;
+
+        assert!(is_sorted_by_key(&indices(Order::WestEast), |index| coordinates(index).longitude));
+        assert!(is_sorted_by_key(&indices(Order::NorthSouth), |index| -coordinates(index).latitude));
+
+        let from = 10;
+        let by_distance = indices(Order::Distance(from));
+        assert_eq!(by_distance[0], from);
+        assert!(is_sorted_by_key(&by_distance, |index| coordinates(index).distance(coordinates(from))));
+    }
+
+    #[test]
+    fn breadth_goes_by_borders_then_distance() {
+        let mut data = vec![RGB8::default(); LAYOUT.leds_count()];
+        let map = Map::new(&LAYOUT, &mut data);
+        let from = 10;
+        let hops = map.distances(from).ok().unwrap();
+        let center = LAYOUT.districts[from].coordinates;
+
+        let order = indices(Order::Breadth(from));
+        assert_eq!(order[0], from);
+        for pair in order.windows(2) {
+            let (a, b) = (hops[pair[0]].unwrap(), hops[pair[1]].unwrap());
+            assert!(a <= b);
+            if a == b {
+                let distance = |index: usize| LAYOUT.districts[index].coordinates.distance(center);
+                assert!(distance(pair[0]) <= distance(pair[1]));
+            }
+        }
+    }
+
+    #[test]
+    fn random_order_depends_only_on_the_seed() {
+        assert_eq!(indices(Order::Random(42)), indices(Order::Random(42)));
+        assert_ne!(indices(Order::Random(42)), indices(Order::Random(43)));
+        assert_ne!(indices(Order::Random(0)), (0..LAYOUT.leds_count()).collect::<Vec<usize>>());
+    }
+
+    #[test]
+    fn unknown_start_is_an_error() {
+        let mut data = vec![RGB8::default(); LAYOUT.leds_count()];
+        let map = Map::new(&LAYOUT, &mut data);
+
+        assert!(map.order(Order::Distance(LAYOUT.leds_count())).is_err());
+        assert!(map.order(Order::Breadth(LAYOUT.leds_count())).is_err());
+    }
+}
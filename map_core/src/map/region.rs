@@ -0,0 +1,35 @@
+use crate::map::name::Named;
+
+/// Region (kraj) grouping several districts.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Region {
+    pub name: &'static str,
+    /// Other names the region may be addressed by, e.g. its NUTS code.
+    pub aliases: &'static [&'static str],
+}
+
+impl Region {
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            aliases: &[],
+        }
+    }
+
+    pub const fn with_aliases(self, aliases: &'static [&'static str]) -> Self {
+        Self {
+            aliases,
+            ..self
+        }
+    }
+}
+
+impl Named for Region {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn aliases(&self) -> &[&str] {
+        self.aliases
+    }
+}
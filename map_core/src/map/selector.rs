@@ -0,0 +1,144 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::map::name::{self, Named};
+use crate::map::{Error, Map};
+
+/// Separates the parts of a selector.
+pub const SEPARATOR: char = ',';
+/// Excludes the LEDs of the part from the selection.
+pub const NEGATION: char = '!';
+/// Stands for any characters of a name, alone it selects all the LEDs.
+pub const WILDCARD: char = '*';
+
+fn parse_index(digits: &[char]) -> Option<usize> {
+    if digits.is_empty() {
+        return None;
+    }
+
+    digits.iter().try_fold(0usize, |index, c| index.checked_mul(10)?.checked_add(c.to_digit(10)? as usize))
+}
+
+/// LEDs of a single part of a selector, without the negation.
+fn resolve_part(map: &Map, part: &[char]) -> Result<Vec<usize>, Error> {
+    let count = map.districts().len();
+
+    if part == [WILDCARD] {
+        return Ok((0..count).collect());
+    }
+
+    if part.contains(&WILDCARD) {
+        let matching: Vec<usize> = map.districts().iter()
+            .enumerate()
+            .filter(|(_, district)| {
+                name::wildcard_matches(district.name(), part)
+                    || district.aliases().iter().any(|alias| name::wildcard_matches(alias, part))
+            })
+            .map(|(index, _)| index)
+            .collect();
+
+        return if matching.is_empty() { Err(Error::NotFound) } else { Ok(matching) };
+    }
+
+    if let Some(dash) = part.iter().position(|c| *c == '-') {
+        if let (Some(first), Some(last)) = (parse_index(&part[..dash]), parse_index(&part[dash + 1..])) {
+            if first > last || last >= count {
+                return Err(Error::NotFound);
+            }
+            return Ok((first..=last).collect());
+        }
+    }
+
+    if let Some(index) = parse_index(part) {
+        return map.district(index).map(|_| vec![index]);
+    }
+
+    map.get_index_by_name(part).map(|index| vec![index])
+}
+
+/// Indices of the LEDs given by the selector, in ascending order.
+///
+/// The selector is a list of parts separated by commas, each part is an index,
+/// an index range `0-10`, a name, a name pattern with wildcards `*HRADEC*` or `*` for all the LEDs.
+/// Parts starting with `!` are excluded from the selection, if there are only such parts,
+/// they are excluded from all the LEDs, e.g. `!PRAHA`.
+pub fn resolve(map: &Map, selector: &[char]) -> Result<Vec<usize>, Error> {
+    let count = map.districts().len();
+    let mut included = vec![false; count];
+    let mut excluded = vec![false; count];
+    let mut any_included = false;
+
+    for part in selector.split(|c| *c == SEPARATOR) {
+        let (negated, part) = match part.split_first() {
+            Some((&NEGATION, rest)) => (true, rest),
+            _ => (false, part)
+        };
+
+        if part.is_empty() {
+            return Err(Error::NotFound);
+        }
+
+        let selected = if negated { &mut excluded } else { &mut included };
+        for index in resolve_part(map, part)? {
+            selected[index] = true;
+        }
+        any_included |= !negated;
+    }
+
+    Ok((0..count)
+        .filter(|index| (included[*index] || !any_included) && !excluded[*index])
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use smart_leds::RGB8;
+    use crate::map::layout::LAYOUT;
+    use crate::map::{Error, Map};
+
+    fn select(selector: &str) -> Result<Vec<usize>, Error> {
+        let mut data = vec![RGB8::default(); LAYOUT.leds_count()];
+        let map = Map::new(&LAYOUT, &mut data);
+        let selector: Vec<char> = selector.chars().collect();
+        map.select(&selector)
+    }
+
+    fn names(selector: &str) -> Vec<&'static str> {
+        select(selector).ok().unwrap().into_iter().map(|index| LAYOUT.districts[index].name).collect()
+    }
+
+    #[test]
+    fn selects_indices_and_ranges() {
+        assert_eq!(select("3").ok(), Some(vec![3]));
+        assert_eq!(select("2-4,0").ok(), Some(vec![0, 2, 3, 4]));
+        assert_eq!(select("4-2").ok(), None);
+        assert_eq!(select("0-99999").ok(), None);
+        assert!(matches!(select("99999"), Err(Error::NotFound)));
+    }
+
+    #[test]
+    fn selects_names_and_patterns() {
+        assert_eq!(names("BRNO,PRAHA"), vec!["PRAHA", "BRNO"]);
+        assert_eq!(names("*HRADEC*"), vec!["HRADEC_KRALOVE", "JINDRICHUV_HRADEC"]);
+        assert_eq!(names("CESK*"), vec!["CESKA_LIPA", "CESKE_BUDEJOVICE", "CESKY_KRUMLOV"]);
+        assert_eq!(select("*").ok().map(|all| all.len()), Some(LAYOUT.leds_count()));
+        assert!(matches!(select("NOWHERE*"), Err(Error::NotFound)));
+    }
+
+    #[test]
+    fn excludes_negated_parts() {
+        let all_but_praha = select("!PRAHA").ok().unwrap();
+        assert_eq!(all_but_praha.len(), LAYOUT.leds_count() - 1);
+        assert!(!names("!PRAHA").contains(&"PRAHA"));
+        assert_eq!(names("CESK*,!CESKY_KRUMLOV"), vec!["CESKA_LIPA", "CESKE_BUDEJOVICE"]);
+        assert!(select("BRNO,!BRNO").ok().unwrap().is_empty());
+    }
+
+    #[test]
+    fn rejects_empty_parts() {
+        assert!(matches!(select(""), Err(Error::NotFound)));
+        assert!(matches!(select("BRNO,"), Err(Error::NotFound)));
+        assert!(matches!(select("!"), Err(Error::NotFound)));
+    }
+}
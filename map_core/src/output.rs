@@ -0,0 +1,139 @@
+pub mod color_correction;
+pub mod power_limiter;
+
+use smart_leds::RGB8;
+use crate::output::color_correction::ColorCorrection;
+use crate::output::power_limiter::PowerLimiter;
+
+/// Settings of the path from the composited frame to the strip.
+pub struct OutputSettings {
+    correction: ColorCorrection,
+    brightness: u8,
+    limiter: PowerLimiter,
+}
+
+impl Default for OutputSettings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OutputSettings {
+    pub fn new() -> Self {
+        Self {
+            correction: ColorCorrection::new(),
+            brightness: 255,
+            limiter: PowerLimiter::new(),
+        }
+    }
+
+    pub fn correction(&self) -> &ColorCorrection {
+        &self.correction
+    }
+
+    pub fn correction_mut(&mut self) -> &mut ColorCorrection {
+        &mut self.correction
+    }
+
+    /// Brightness of all the LEDs, 255 is the full brightness.
+    pub fn brightness(&self) -> u8 {
+        self.brightness
+    }
+
+    pub fn set_brightness(&mut self, brightness: u8) {
+        self.brightness = brightness;
+    }
+
+    pub fn limiter(&self) -> &PowerLimiter {
+        &self.limiter
+    }
+
+    pub fn limiter_mut(&mut self) -> &mut PowerLimiter {
+        &mut self.limiter
+    }
+
+    /// Colors to be sent to the strip for the given frame, corrected,
+    /// dimmed by the brightness and scaled down to fit the current budget.
+    pub fn apply<'a, T: Iterator<Item=RGB8> + Clone + 'a>(&'a self, frame: T) -> impl Iterator<Item=RGB8> + 'a {
+        let factor = self.factor(frame.clone());
+        frame.map(move |rgb| dim(self.correction.apply(rgb), factor))
+    }
+
+    /// The frame dimmed the same way as by `apply`, but without the color correction,
+    /// for showing the frame on a screen.
+    pub fn preview<'a, T: Iterator<Item=RGB8> + Clone + 'a>(&'a self, frame: T) -> impl Iterator<Item=RGB8> + 'a {
+        let factor = self.factor(frame.clone());
+        frame.map(move |rgb| dim(rgb, factor))
+    }
+
+    /// Estimated current of the LEDs showing the frame in milliamps.
+    pub fn estimate<T: Iterator<Item=RGB8>>(&self, frame: T) -> u32 {
+        self.limiter.estimate(self.dimmed(frame))
+    }
+
+    /// Factor of both the brightness and the current limit.
+    fn factor<T: Iterator<Item=RGB8>>(&self, frame: T) -> u8 {
+        let limit = self.limiter.factor(self.dimmed(frame));
+        scale(self.brightness, limit)
+    }
+
+    fn dimmed<'a, T: Iterator<Item=RGB8> + 'a>(&'a self, frame: T) -> impl Iterator<Item=RGB8> + 'a {
+        let brightness = self.brightness;
+        frame.map(move |rgb| dim(self.correction.apply(rgb), brightness))
+    }
+}
+
+/// Multiplies the value by the factor / 255.
+pub(crate) fn scale(value: u8, factor: u8) -> u8 {
+    ((value as u16 * factor as u16 + 127) / 255) as u8
+}
+
+fn dim(rgb: RGB8, factor: u8) -> RGB8 {
+    RGB8 {
+        r: scale(rgb.r, factor),
+        g: scale(rgb.g, factor),
+        b: scale(rgb.b, factor),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use core::iter::repeat_n;
+    use smart_leds::RGB8;
+    use super::OutputSettings;
+
+    const WHITE: RGB8 = RGB8 { r: 255, g: 255, b: 255 };
+
+    fn applied(settings: &OutputSettings, leds: usize) -> Vec<RGB8> {
+        settings.apply(repeat_n(WHITE, leds)).collect()
+    }
+
+    #[test]
+    fn dims_by_the_brightness() {
+        let mut settings = OutputSettings::new();
+        settings.limiter_mut().set_budget(None);
+        assert!(applied(&settings, 10).iter().all(|rgb| *rgb == WHITE));
+
+        settings.set_brightness(128);
+        assert!(applied(&settings, 10).iter().all(|rgb| *rgb == RGB8 { r: 128, g: 128, b: 128 }));
+
+        settings.set_brightness(0);
+        assert!(applied(&settings, 10).iter().all(|rgb| *rgb == RGB8::default()));
+    }
+
+    #[test]
+    fn limits_the_dimmed_frame() {
+        let mut settings = OutputSettings::new();
+        settings.limiter_mut().set_budget(Some(310));
+
+        // 610 mA at full brightness, half of the channels fit in the budget
+        let limited = applied(&settings, 10);
+        assert!(limited[0].r < 128);
+        assert!(settings.limiter().estimate(limited.into_iter()) <= 310);
+
+        // dimmed by the brightness, the frame already fits
+        settings.set_brightness(100);
+        assert!(applied(&settings, 10).iter().all(|rgb| *rgb == RGB8 { r: 100, g: 100, b: 100 }));
+    }
+}
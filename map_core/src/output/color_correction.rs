@@ -0,0 +1,116 @@
+use libm::{powf, roundf};
+use smart_leds::RGB8;
+use crate::output::scale;
+
+/// Gamma of the WS2812B LEDs, makes low values as dim as they look on a screen.
+pub const DEFAULT_GAMMA: f32 = 2.2;
+/// Neutral scale of the channels, tune it with BALANCE when the white of the strip looks tinted.
+pub const DEFAULT_BALANCE: RGB8 = RGB8 { r: 255, g: 255, b: 255 };
+
+/// Converts the colors as they are meant to look to the values sent to the LEDs,
+/// first through the gamma lookup table, then scaling each channel by the white balance.
+pub struct ColorCorrection {
+    gamma: f32,
+    lut: [u8; 256],
+    balance: RGB8,
+}
+
+impl Default for ColorCorrection {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ColorCorrection {
+    pub fn new() -> Self {
+        let mut correction = Self {
+            gamma: 1.0,
+            lut: [0; 256],
+            balance: DEFAULT_BALANCE,
+        };
+        correction.set_gamma(DEFAULT_GAMMA);
+        correction
+    }
+
+    pub fn gamma(&self) -> f32 {
+        self.gamma
+    }
+
+    /// Gamma of 1.0 turns the correction off.
+    pub fn set_gamma(&mut self, gamma: f32) {
+        self.gamma = gamma;
+        for (value, corrected) in self.lut.iter_mut().enumerate() {
+            *corrected = roundf(powf(value as f32 / 255.0, gamma) * 255.0) as u8;
+        }
+    }
+
+    pub fn balance(&self) -> RGB8 {
+        self.balance
+    }
+
+    /// Each channel is multiplied by the given value / 255.
+    pub fn set_balance(&mut self, balance: RGB8) {
+        self.balance = balance;
+    }
+
+    pub fn apply(&self, rgb: RGB8) -> RGB8 {
+        RGB8 {
+            r: scale(self.lut[rgb.r as usize], self.balance.r),
+            g: scale(self.lut[rgb.g as usize], self.balance.g),
+            b: scale(self.lut[rgb.b as usize], self.balance.b),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use smart_leds::RGB8;
+    use super::{ColorCorrection, DEFAULT_BALANCE, DEFAULT_GAMMA};
+
+    fn grey(value: u8) -> RGB8 {
+        RGB8 { r: value, g: value, b: value }
+    }
+
+    #[test]
+    fn keeps_the_endpoints() {
+        for gamma in [DEFAULT_GAMMA, 1.0, 0.5, 3.0] {
+            let mut correction = ColorCorrection::new();
+            correction.set_gamma(gamma);
+            assert_eq!(correction.apply(grey(0)), grey(0));
+            assert_eq!(correction.apply(grey(255)), grey(255));
+        }
+    }
+
+    #[test]
+    fn gamma_of_one_is_the_identity() {
+        let mut correction = ColorCorrection::new();
+        correction.set_gamma(1.0);
+
+        for value in 0..=255 {
+            assert_eq!(correction.apply(grey(value)), grey(value));
+        }
+    }
+
+    #[test]
+    fn gamma_dims_the_middle() {
+        let correction = ColorCorrection::new();
+        assert_eq!(correction.gamma(), DEFAULT_GAMMA);
+        assert_eq!(correction.apply(grey(128)), grey(56));
+
+        let lut: [u8; 256] = core::array::from_fn(|value| correction.apply(grey(value as u8)).r);
+        assert!(lut.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[test]
+    fn balance_scales_each_channel() {
+        let mut correction = ColorCorrection::new();
+        correction.set_gamma(1.0);
+        assert_eq!(correction.balance(), DEFAULT_BALANCE);
+        assert_eq!(correction.apply(grey(200)), grey(200));
+
+        correction.set_balance(RGB8 { r: 255, g: 128, b: 0 });
+        assert_eq!(correction.apply(grey(255)), RGB8 { r: 255, g: 128, b: 0 });
+        assert_eq!(correction.apply(grey(100)), RGB8 { r: 100, g: 50, b: 0 });
+        assert_eq!(correction.apply(RGB8 { r: 0, g: 200, b: 200 }), RGB8 { r: 0, g: 100, b: 0 });
+    }
+}
@@ -0,0 +1,121 @@
+use smart_leds::RGB8;
+
+/// Current drawn by one channel of a WS2812B LED at full intensity.
+pub const MILLIAMPS_PER_CHANNEL: u32 = 20;
+/// Current drawn by a WS2812B LED when it is off.
+pub const IDLE_MILLIAMPS_PER_LED: u32 = 1;
+/// Budget of a board powered from an USB-C port.
+pub const DEFAULT_BUDGET: u32 = 1500;
+/// Highest budget accepted, far more than any power supply of the board gives.
+pub const MAX_BUDGET: u32 = 100_000;
+
+/// Scales the frames down so that the estimated current of the LEDs
+/// stays within the budget.
+pub struct PowerLimiter {
+    budget: Option<u32>,
+}
+
+impl Default for PowerLimiter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PowerLimiter {
+    pub fn new() -> Self {
+        Self {
+            budget: Some(DEFAULT_BUDGET),
+        }
+    }
+
+    /// Budget in milliamps, `None` if the current is not limited.
+    pub fn budget(&self) -> Option<u32> {
+        self.budget
+    }
+
+    /// Budgets above `MAX_BUDGET` are lowered to it.
+    pub fn set_budget(&mut self, budget: Option<u32>) {
+        self.budget = budget.map(|budget| budget.min(MAX_BUDGET));
+    }
+
+    /// Estimated current of the LEDs showing the frame in milliamps.
+    pub fn estimate<T: Iterator<Item=RGB8>>(&self, frame: T) -> u32 {
+        let (leds, channels) = Self::sum(frame);
+        leds * IDLE_MILLIAMPS_PER_LED + channels * MILLIAMPS_PER_CHANNEL / 255
+    }
+
+    /// Factor (0 - 255, 255 keeps the frame as is) the frame
+    /// has to be scaled by to fit the budget.
+    pub fn factor<T: Iterator<Item=RGB8>>(&self, frame: T) -> u8 {
+        let budget = match self.budget {
+            Some(budget) => budget,
+            None => return 255
+        };
+
+        let (leds, channels) = Self::sum(frame);
+        let allowed = budget.saturating_sub(leds * IDLE_MILLIAMPS_PER_LED) as u64 * 255 / MILLIAMPS_PER_CHANNEL as u64;
+
+        if channels as u64 <= allowed {
+            255
+        } else {
+            (allowed * 255 / channels as u64) as u8
+        }
+    }
+
+    /// Number of the LEDs and the sum of all their channels.
+    fn sum<T: Iterator<Item=RGB8>>(frame: T) -> (u32, u32) {
+        frame.fold((0, 0), |(leds, channels), rgb| {
+            (leds + 1, channels + rgb.r as u32 + rgb.g as u32 + rgb.b as u32)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::iter::repeat_n;
+    use smart_leds::RGB8;
+    use super::{PowerLimiter, MAX_BUDGET};
+
+    const WHITE: RGB8 = RGB8 { r: 255, g: 255, b: 255 };
+
+    fn limiter(budget: Option<u32>) -> PowerLimiter {
+        let mut limiter = PowerLimiter::new();
+        limiter.set_budget(budget);
+        limiter
+    }
+
+    #[test]
+    fn estimates_the_current() {
+        let limiter = limiter(None);
+
+        assert_eq!(limiter.estimate(repeat_n(RGB8::default(), 10)), 10);
+        assert_eq!(limiter.estimate(repeat_n(WHITE, 10)), 610);
+    }
+
+    #[test]
+    fn keeps_frames_under_the_budget() {
+        assert_eq!(limiter(Some(1500)).factor(repeat_n(WHITE, 10)), 255);
+        assert_eq!(limiter(Some(610)).factor(repeat_n(WHITE, 10)), 255);
+        assert_eq!(limiter(None).factor(repeat_n(WHITE, 1000)), 255);
+    }
+
+    #[test]
+    fn scales_frames_over_the_budget_down() {
+        // 10 mA for the idle LEDs, the rest is half of the 600 mA of the channels
+        assert_eq!(limiter(Some(310)).factor(repeat_n(WHITE, 10)), 127);
+        assert_eq!(limiter(Some(5)).factor(repeat_n(WHITE, 10)), 0);
+
+        let limiter = limiter(Some(310));
+        let factor = limiter.factor(repeat_n(WHITE, 10));
+        let dimmed = RGB8 { r: factor, g: factor, b: factor };
+        assert!(limiter.estimate(repeat_n(dimmed, 10)) <= 310);
+    }
+
+    #[test]
+    fn huge_budgets_do_not_overflow() {
+        let limiter = limiter(Some(u32::MAX));
+
+        assert_eq!(limiter.budget(), Some(MAX_BUDGET));
+        assert_eq!(limiter.factor(repeat_n(WHITE, 72)), 255);
+    }
+}
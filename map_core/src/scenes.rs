@@ -0,0 +1,107 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use smart_leds::RGB8;
+use crate::map::{name, Map};
+
+/// Most scenes kept at once, each takes three bytes per LED.
+pub const MAX_SCENES: usize = 16;
+
+/// Base colors of the map saved under a name.
+pub struct Scene {
+    name: String,
+    colors: Vec<RGB8>,
+}
+
+impl Scene {
+    pub fn new(name: String, colors: Vec<RGB8>) -> Self {
+        Self {
+            name,
+            colors
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn colors(&self) -> &[RGB8] {
+        &self.colors
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum SceneError {
+    NotFound,
+    Full,
+    /// The scene was saved for a board with a different number of LEDs.
+    WrongSize,
+}
+
+#[derive(Default)]
+pub struct Scenes {
+    scenes: Vec<Scene>,
+}
+
+impl Scenes {
+    pub fn new() -> Self {
+        Self {
+            scenes: Vec::new()
+        }
+    }
+
+    pub fn scenes(&self) -> &[Scene] {
+        &self.scenes
+    }
+
+    /// Index of the scene with the name, compared the same way as the names of the districts.
+    pub fn find(&self, name: &[char]) -> Option<usize> {
+        self.scenes.iter().position(|scene| name::matches(&scene.name, name))
+    }
+
+    /// Saves the base colors of the map, overwriting the scene with the same name.
+    pub fn save(&mut self, name: &[char], map: &Map) -> Result<(), SceneError> {
+        let colors: Vec<RGB8> = map.get_map().copied().collect();
+
+        if let Some(index) = self.find(name) {
+            self.scenes[index].colors = colors;
+            return Ok(());
+        }
+
+        if self.scenes.len() >= MAX_SCENES {
+            return Err(SceneError::Full);
+        }
+
+        self.scenes.push(Scene::new(name.iter().map(|c| name::fold(*c)).collect(), colors));
+        Ok(())
+    }
+
+    /// Adds the scene, replacing the one with the same name.
+    pub fn insert(&mut self, scene: Scene) -> Result<(), SceneError> {
+        let name: Vec<char> = scene.name.chars().collect();
+        match self.find(&name) {
+            Some(index) => self.scenes[index] = scene,
+            None if self.scenes.len() >= MAX_SCENES => return Err(SceneError::Full),
+            None => self.scenes.push(scene)
+        }
+        Ok(())
+    }
+
+    /// Sets the base colors of the map to the scene.
+    pub fn load(&self, name: &[char], map: &mut Map) -> Result<(), SceneError> {
+        let scene = &self.scenes[self.find(name).ok_or(SceneError::NotFound)?];
+        if scene.colors.len() != map.districts().len() {
+            return Err(SceneError::WrongSize);
+        }
+
+        for (led, color) in map.get_map_mut().zip(scene.colors.iter()) {
+            *led = *color;
+        }
+        Ok(())
+    }
+
+    pub fn delete(&mut self, name: &[char]) -> Result<(), SceneError> {
+        let index = self.find(name).ok_or(SceneError::NotFound)?;
+        self.scenes.remove(index);
+        Ok(())
+    }
+}
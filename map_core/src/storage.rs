@@ -0,0 +1,23 @@
+pub mod memory_storage;
+pub mod flash_storage;
+
+use alloc::vec::Vec;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum StorageError {
+    KeyTooLong,
+    ValueTooLarge,
+    /// There is no space left for the value.
+    Full,
+    /// Reading, writing or erasing the flash failed.
+    Flash,
+}
+
+/// Key-value storage keeping the configuration and the scenes over power cycles.
+pub trait Storage {
+    fn get(&self, key: &str) -> Option<&[u8]>;
+    /// Storing the same value the key already has does not write anything.
+    fn set(&mut self, key: &str, value: &[u8]) -> Result<(), StorageError>;
+    fn remove(&mut self, key: &str) -> Result<(), StorageError>;
+    fn keys(&self) -> Vec<&str>;
+}
@@ -0,0 +1,509 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use embedded_storage::nor_flash::NorFlash;
+use crate::storage::{Storage, StorageError};
+
+/// Marks the sectors written by the storage, followed by the sequence number of the sector.
+const MAGIC: u32 = 0x4D41_5031;
+/// Key length of the space not written yet.
+const ERASED: u8 = 0xFF;
+/// Value length of a removed key.
+const REMOVED: u16 = 0xFFFF;
+/// Key length, checksum and value length.
+const RECORD_HEADER: usize = 4;
+
+struct Entry {
+    value: Vec<u8>,
+    /// Sector holding the latest record of the key.
+    sector: usize,
+}
+
+/// Storage writing the values to a NOR flash as a log.
+///
+/// Every change is appended as a new record after the previous ones, so a sector
+/// is erased only once it is full, and the sectors are used in turns to spread the wear.
+/// When the last free sector starts being used, the values still living
+/// in the oldest sector are copied over and the oldest sector is erased.
+/// All the values are kept in memory as well, so reading does not touch the flash.
+pub struct FlashStorage<F> {
+    flash: F,
+    offset: u32,
+    /// Sequence number of each sector, `None` for the erased ones.
+    sequences: Vec<Option<u32>>,
+    entries: BTreeMap<String, Entry>,
+    /// Sector the records are appended to.
+    head: Option<usize>,
+    /// Where the next record is written in the head sector.
+    position: usize,
+}
+
+impl<F: NorFlash> FlashStorage<F> {
+    /// Uses `sectors` sectors of the flash starting at `offset`, at least two are needed.
+    /// The values already stored there are loaded, sectors not written by the storage are erased.
+    pub fn new(flash: F, offset: u32, sectors: usize) -> Result<Self, StorageError> {
+        assert!(sectors >= 2, "The storage needs at least two sectors.");
+
+        let mut storage = Self {
+            flash,
+            offset,
+            sequences: vec![None; sectors],
+            entries: BTreeMap::new(),
+            head: None,
+            position: 0,
+        };
+        storage.load()?;
+        Ok(storage)
+    }
+
+    pub fn into_inner(self) -> F {
+        self.flash
+    }
+
+    fn alignment() -> usize {
+        F::WRITE_SIZE.max(F::READ_SIZE).max(4)
+    }
+
+    fn align(length: usize) -> usize {
+        let alignment = Self::alignment();
+        length.div_ceil(alignment) * alignment
+    }
+
+    fn sector_header() -> usize {
+        Self::align(8)
+    }
+
+    fn address(&self, sector: usize, position: usize) -> u32 {
+        self.offset + (sector * F::ERASE_SIZE + position) as u32
+    }
+
+    fn checksum(value_length: u16, key: &[u8], value: &[u8]) -> u8 {
+        value_length.to_le_bytes().iter()
+            .chain(key)
+            .chain(value)
+            .fold(0x5Au8, |checksum, byte| checksum.rotate_left(1) ^ byte)
+    }
+
+    fn load(&mut self) -> Result<(), StorageError> {
+        let mut header = vec![0u8; Self::sector_header()];
+        for sector in 0..self.sequences.len() {
+            self.flash.read(self.address(sector, 0), &mut header).map_err(|_| StorageError::Flash)?;
+
+            if header[..4] == MAGIC.to_le_bytes() {
+                self.sequences[sector] = Some(u32::from_le_bytes([header[4], header[5], header[6], header[7]]));
+            } else if header.iter().any(|byte| *byte != 0xFF) {
+                self.erase(sector)?;
+            }
+        }
+
+        let mut order: Vec<usize> = (0..self.sequences.len()).filter(|sector| self.sequences[*sector].is_some()).collect();
+        order.sort_by_key(|sector| self.sequences[*sector]);
+
+        let mut data = vec![0u8; F::ERASE_SIZE];
+        for sector in order {
+            self.flash.read(self.address(sector, 0), &mut data).map_err(|_| StorageError::Flash)?;
+            self.position = self.replay(sector, &data);
+            self.head = Some(sector);
+        }
+
+        // power was lost before the oldest sector got erased
+        if self.sequences.iter().all(|sequence| sequence.is_some()) {
+            self.collect_oldest().ok();
+        }
+
+        Ok(())
+    }
+
+    /// Applies the records of the sector, returns the position after the last one.
+    fn replay(&mut self, sector: usize, data: &[u8]) -> usize {
+        let mut position = Self::sector_header();
+
+        while position + RECORD_HEADER <= data.len() {
+            let key_length = data[position] as usize;
+            if data[position] == ERASED {
+                return position;
+            }
+
+            let value_length = u16::from_le_bytes([data[position + 2], data[position + 3]]);
+            let stored_length = if value_length == REMOVED { 0 } else { value_length as usize };
+            let key_start = position + RECORD_HEADER;
+            let value_start = key_start + key_length;
+            let end = value_start + stored_length;
+            if end > data.len() {
+                break;
+            }
+
+            let key = &data[key_start..value_start];
+            let value = &data[value_start..end];
+            if data[position + 1] == Self::checksum(value_length, key, value) {
+                if let Ok(key) = core::str::from_utf8(key) {
+                    if value_length == REMOVED {
+                        self.entries.remove(key);
+                    } else {
+                        self.entries.insert(String::from(key), Entry { value: Vec::from(value), sector });
+                    }
+                }
+            }
+
+            position += Self::align(end - position);
+        }
+
+        // the rest of the sector is not usable
+        data.len()
+    }
+
+    fn erase(&mut self, sector: usize) -> Result<(), StorageError> {
+        self.flash.erase(self.address(sector, 0), self.address(sector + 1, 0)).map_err(|_| StorageError::Flash)?;
+        self.sequences[sector] = None;
+        Ok(())
+    }
+
+    /// Appends the record to the head sector if it fits there.
+    fn append(&mut self, key: &str, value: Option<&[u8]>) -> Result<bool, StorageError> {
+        let head = match self.head {
+            Some(head) => head,
+            None => return Ok(false)
+        };
+
+        let stored = value.unwrap_or(&[]);
+        let length = Self::align(RECORD_HEADER + key.len() + stored.len());
+        if self.position + length > F::ERASE_SIZE {
+            return Ok(false);
+        }
+
+        let value_length = value.map(|value| value.len() as u16).unwrap_or(REMOVED);
+        let mut record = Vec::with_capacity(length);
+        record.push(key.len() as u8);
+        record.push(Self::checksum(value_length, key.as_bytes(), stored));
+        record.extend_from_slice(&value_length.to_le_bytes());
+        record.extend_from_slice(key.as_bytes());
+        record.extend_from_slice(stored);
+        record.resize(length, 0xFF);
+
+        let address = self.address(head, self.position);
+        self.position += length;
+        self.flash.write(address, &record).map_err(|_| StorageError::Flash)?;
+
+        match value {
+            Some(value) => self.entries.insert(String::from(key), Entry { value: Vec::from(value), sector: head }),
+            None => self.entries.remove(key)
+        };
+        Ok(true)
+    }
+
+    /// Starts appending to the next erased sector,
+    /// collects the oldest sector if no other erased one is left.
+    /// If the collection fails, the sector is erased again and the head stays where it was,
+    /// so that there is always an erased sector to collect into.
+    fn advance(&mut self) -> Result<(), StorageError> {
+        let count = self.sequences.len();
+        let start = self.head.map(|head| head + 1).unwrap_or(0);
+        let next = (0..count)
+            .map(|i| (start + i) % count)
+            .find(|sector| self.sequences[*sector].is_none())
+            .ok_or(StorageError::Full)?;
+        let sequence = self.sequences.iter().flatten().max().map(|sequence| sequence.wrapping_add(1)).unwrap_or(0);
+
+        let mut header = vec![0xFFu8; Self::sector_header()];
+        header[..4].copy_from_slice(&MAGIC.to_le_bytes());
+        header[4..8].copy_from_slice(&sequence.to_le_bytes());
+        let previous = (self.head, self.position);
+        self.sequences[next] = Some(sequence);
+        self.head = Some(next);
+        self.position = Self::sector_header();
+
+        let claimed = self.flash.write(self.address(next, 0), &header).map_err(|_| StorageError::Flash);
+        let collected = claimed.and_then(|_| {
+            if self.sequences.iter().all(|sequence| sequence.is_some()) {
+                self.collect_oldest()
+            } else {
+                Ok(())
+            }
+        });
+
+        if let Err(err) = collected {
+            (self.head, self.position) = previous;
+            self.erase(next)?;
+            return Err(err);
+        }
+
+        Ok(())
+    }
+
+    /// Copies the values living in the oldest sector to the head and erases it.
+    /// If not all of them can be copied, they are kept living in the oldest sector.
+    fn collect_oldest(&mut self) -> Result<(), StorageError> {
+        let oldest = (0..self.sequences.len())
+            .filter(|sector| Some(*sector) != self.head)
+            .min_by_key(|sector| self.sequences[*sector])
+            .ok_or(StorageError::Full)?;
+
+        let living: Vec<(String, Vec<u8>)> = self.entries.iter()
+            .filter(|(_, entry)| entry.sector == oldest)
+            .map(|(key, entry)| (key.clone(), entry.value.clone()))
+            .collect();
+
+        for (key, value) in &living {
+            let appended = self.append(key, Some(value));
+            if !matches!(appended, Ok(true)) {
+                for (key, _) in &living {
+                    if let Some(entry) = self.entries.get_mut(key) {
+                        entry.sector = oldest;
+                    }
+                }
+                return Err(appended.err().unwrap_or(StorageError::Full));
+            }
+        }
+
+        self.erase(oldest)
+    }
+
+    fn write(&mut self, key: &str, value: Option<&[u8]>) -> Result<(), StorageError> {
+        if key.len() >= ERASED as usize {
+            return Err(StorageError::KeyTooLong);
+        }
+
+        let length = Self::align(RECORD_HEADER + key.len() + value.map(|value| value.len()).unwrap_or(0));
+        if value.is_some_and(|value| value.len() >= REMOVED as usize) || length > F::ERASE_SIZE - Self::sector_header() {
+            return Err(StorageError::ValueTooLarge);
+        }
+
+        if self.append(key, value)? {
+            return Ok(());
+        }
+
+        // the sectors being collected may be full of living values,
+        // the garbage of the other ones is freed by the next collections
+        for _ in 0..self.sequences.len() {
+            self.advance()?;
+            if self.append(key, value)? {
+                return Ok(());
+            }
+        }
+
+        Err(StorageError::Full)
+    }
+}
+
+impl<F: NorFlash> Storage for FlashStorage<F> {
+    fn get(&self, key: &str) -> Option<&[u8]> {
+        self.entries.get(key).map(|entry| entry.value.as_slice())
+    }
+
+    fn set(&mut self, key: &str, value: &[u8]) -> Result<(), StorageError> {
+        if self.get(key) == Some(value) {
+            return Ok(());
+        }
+
+        self.write(key, Some(value))
+    }
+
+    fn remove(&mut self, key: &str) -> Result<(), StorageError> {
+        if self.get(key).is_none() {
+            return Ok(());
+        }
+
+        self.write(key, None)
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.entries.keys().map(|key| key.as_str()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use embedded_storage::nor_flash::{ErrorType, NorFlash, NorFlashErrorKind, ReadNorFlash};
+    use crate::storage::{Storage, StorageError};
+    use super::FlashStorage;
+
+    const SECTOR: usize = 256;
+    const SECTORS: usize = 3;
+
+    /// Flash kept in memory, writing only clears bits like on a NOR flash.
+    struct RamFlash {
+        data: Vec<u8>,
+        writes: usize,
+        /// Writes allowed before they start failing, `None` for no limit.
+        writes_left: Option<usize>,
+    }
+
+    impl RamFlash {
+        fn new() -> Self {
+            Self {
+                data: vec![0xFF; SECTOR * SECTORS],
+                writes: 0,
+                writes_left: None,
+            }
+        }
+
+        fn erased_sectors(&self) -> usize {
+            self.data.chunks(SECTOR).filter(|sector| sector.iter().all(|byte| *byte == 0xFF)).count()
+        }
+    }
+
+    impl ErrorType for RamFlash {
+        type Error = NorFlashErrorKind;
+    }
+
+    impl ReadNorFlash for RamFlash {
+        const READ_SIZE: usize = 1;
+
+        fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+            let offset = offset as usize;
+            bytes.copy_from_slice(&self.data[offset..offset + bytes.len()]);
+            Ok(())
+        }
+
+        fn capacity(&self) -> usize {
+            self.data.len()
+        }
+    }
+
+    impl NorFlash for RamFlash {
+        const WRITE_SIZE: usize = 4;
+        const ERASE_SIZE: usize = SECTOR;
+
+        fn erase(&mut self, from: u32, to: u32) -> Result<(), Self::Error> {
+            self.data[from as usize..to as usize].fill(0xFF);
+            Ok(())
+        }
+
+        fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+            if let Some(left) = &mut self.writes_left {
+                if *left == 0 {
+                    return Err(NorFlashErrorKind::Other);
+                }
+                *left -= 1;
+            }
+
+            self.writes += 1;
+            let offset = offset as usize;
+            for (current, byte) in self.data[offset..offset + bytes.len()].iter_mut().zip(bytes) {
+                *current &= byte;
+            }
+            Ok(())
+        }
+    }
+
+    fn mount(flash: RamFlash) -> FlashStorage<RamFlash> {
+        FlashStorage::new(flash, 0, SECTORS).unwrap()
+    }
+
+    fn remount(storage: FlashStorage<RamFlash>) -> FlashStorage<RamFlash> {
+        mount(storage.into_inner())
+    }
+
+    /// Value taking a third of a sector with its record.
+    fn value(fill: u8) -> Vec<u8> {
+        vec![fill; 60]
+    }
+
+    fn key(index: usize) -> alloc::string::String {
+        alloc::format!("k{}", index)
+    }
+
+    #[test]
+    fn values_survive_remount() {
+        let mut storage = mount(RamFlash::new());
+        storage.set("a", &[1, 2, 3]).unwrap();
+        storage.set("b", &[4]).unwrap();
+        storage.remove("a").unwrap();
+        storage.set("c", &[]).unwrap();
+
+        let storage = remount(storage);
+        assert_eq!(storage.get("a"), None);
+        assert_eq!(storage.get("b"), Some(&[4u8][..]));
+        assert_eq!(storage.get("c"), Some(&[][..]));
+        assert_eq!(storage.keys(), vec!["b", "c"]);
+    }
+
+    #[test]
+    fn unchanged_value_is_not_written() {
+        let mut storage = mount(RamFlash::new());
+        storage.set("a", &[1]).unwrap();
+        let writes = storage.flash.writes;
+
+        storage.set("a", &[1]).unwrap();
+        storage.remove("missing").unwrap();
+        assert_eq!(storage.flash.writes, writes);
+    }
+
+    #[test]
+    fn rejects_long_keys_and_large_values() {
+        let mut storage = mount(RamFlash::new());
+        assert_eq!(storage.set(&"k".repeat(255), &[1]), Err(StorageError::KeyTooLong));
+        assert_eq!(storage.set("a", &[0; SECTOR]), Err(StorageError::ValueTooLarge));
+    }
+
+    #[test]
+    fn rotation_keeps_an_erased_sector() {
+        let mut storage = mount(RamFlash::new());
+        for i in 0..100 {
+            storage.set("b", &value(i)).unwrap();
+        }
+
+        let storage = remount(storage);
+        assert_eq!(storage.get("b"), Some(&value(99)[..]));
+        assert_eq!(storage.into_inner().erased_sectors(), 1);
+    }
+
+    #[test]
+    fn collection_moves_living_values() {
+        let mut storage = mount(RamFlash::new());
+        storage.set("a", &[7]).unwrap();
+        for i in 0..50 {
+            storage.set("b", &value(i)).unwrap();
+        }
+
+        let storage = remount(storage);
+        assert_eq!(storage.get("a"), Some(&[7u8][..]));
+        assert_eq!(storage.get("b"), Some(&value(49)[..]));
+    }
+
+    #[test]
+    fn full_storage_stays_usable() {
+        let mut storage = mount(RamFlash::new());
+        let stored = (0..20).take_while(|i| storage.set(&key(*i), &value(*i as u8)).is_ok()).count();
+        assert_eq!(stored, 6);
+        assert_eq!(storage.set(&key(stored), &value(0)), Err(StorageError::Full));
+
+        let mut storage = remount(storage);
+        for i in 0..stored {
+            assert_eq!(storage.get(&key(i)), Some(&value(i as u8)[..]));
+        }
+
+        storage.remove(&key(0)).unwrap();
+        storage.set("new", &value(0)).unwrap();
+
+        let storage = remount(storage);
+        assert_eq!(storage.get(&key(0)), None);
+        assert_eq!(storage.get("new"), Some(&value(0)[..]));
+        assert_eq!(storage.into_inner().erased_sectors(), 1);
+    }
+
+    #[test]
+    fn failed_collection_is_rolled_back() {
+        let mut storage = mount(RamFlash::new());
+        for i in 0..6 {
+            storage.set(&key(i), &value(i as u8)).unwrap();
+        }
+
+        // the header of the last erased sector gets written, copying the oldest values fails
+        storage.flash.writes_left = Some(1);
+        assert_eq!(storage.set(&key(6), &value(6)), Err(StorageError::Flash));
+        storage.flash.writes_left = None;
+
+        storage.remove(&key(0)).unwrap();
+        storage.set(&key(6), &value(6)).unwrap();
+
+        let storage = remount(storage);
+        assert_eq!(storage.get(&key(0)), None);
+        for i in 1..7 {
+            assert_eq!(storage.get(&key(i)), Some(&value(i as u8)[..]));
+        }
+    }
+}
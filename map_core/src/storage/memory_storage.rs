@@ -0,0 +1,38 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use crate::storage::{Storage, StorageError};
+
+/// Storage keeping the values only until the board is restarted.
+#[derive(Default)]
+pub struct MemoryStorage {
+    values: BTreeMap<String, Vec<u8>>,
+}
+
+impl MemoryStorage {
+    pub fn new() -> Self {
+        Self {
+            values: BTreeMap::new()
+        }
+    }
+}
+
+impl Storage for MemoryStorage {
+    fn get(&self, key: &str) -> Option<&[u8]> {
+        self.values.get(key).map(|value| value.as_slice())
+    }
+
+    fn set(&mut self, key: &str, value: &[u8]) -> Result<(), StorageError> {
+        self.values.insert(String::from(key), Vec::from(value));
+        Ok(())
+    }
+
+    fn remove(&mut self, key: &str) -> Result<(), StorageError> {
+        self.values.remove(key);
+        Ok(())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.values.keys().map(|key| key.as_str()).collect()
+    }
+}
@@ -0,0 +1,55 @@
+use core::slice::IterMut;
+use fugit::NanosDurationU32;
+use smart_leds::RGB8;
+
+pub struct StripTiming {
+    one_high_duration: NanosDurationU32,
+    one_low_duration: NanosDurationU32,
+    zero_high_duration: NanosDurationU32,
+    zero_low_duration: NanosDurationU32,
+}
+
+impl StripTiming {
+    pub fn new(one_high_duration: NanosDurationU32,
+               one_low_duration: NanosDurationU32,
+               zero_high_duration: NanosDurationU32,
+               zero_low_duration: NanosDurationU32) -> Self {
+        StripTiming {
+            one_high_duration,
+            one_low_duration,
+            zero_high_duration,
+            zero_low_duration,
+        }
+    }
+
+    pub fn high_duration(&self, bit: bool) -> NanosDurationU32 {
+        if bit { self.one_high_duration } else { self.zero_high_duration }
+    }
+
+    pub fn low_duration(&self, bit: bool) -> NanosDurationU32 {
+        if bit { self.one_low_duration } else { self.zero_low_duration }
+    }
+}
+
+/// Encodes the colors into one pulse code per bit, in the GRB order
+/// WS2812B expects, most significant bit first. The pulse codes are
+/// produced by `bit_to_pulse_code` and the sequence is terminated by a zero.
+pub fn encode<T, I, F>(iterator: T, buffer: &mut [u32], bit_to_pulse_code: F)
+    where T: Iterator<Item=I>, I: Into<RGB8>, F: Fn(bool) -> u32
+{
+    let mut iter_mut = buffer.iter_mut();
+    for item in iterator {
+        let rgb = item.into();
+        byte_to_pulse_code(rgb.g, &mut iter_mut, &bit_to_pulse_code);
+        byte_to_pulse_code(rgb.r, &mut iter_mut, &bit_to_pulse_code);
+        byte_to_pulse_code(rgb.b, &mut iter_mut, &bit_to_pulse_code);
+    }
+    *iter_mut.next().unwrap() = 0;
+}
+
+fn byte_to_pulse_code<F: Fn(bool) -> u32>(byte: u8, data: &mut IterMut<u32>, bit_to_pulse_code: &F) {
+    for i in 0..8u8 {
+        let bit = (byte & (1 << (7 - i))) > 0;
+        *data.next().unwrap() = bit_to_pulse_code(bit);
+    }
+}
@@ -0,0 +1,83 @@
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+use embedded_storage::nor_flash::{check_erase, check_read, check_write, ErrorType, NorFlash, NorFlashErrorKind, ReadNorFlash};
+
+/// NOR flash emulated by a file, so that the simulator stores the values
+/// the same way as the board does. Like on a real flash, writing can only
+/// clear bits, erasing sets the whole sector back to 0xFF.
+pub struct FileFlash {
+    file: File,
+    capacity: usize,
+}
+
+impl FileFlash {
+    pub const SECTOR_SIZE: usize = 4096;
+
+    /// Opens the file, creating an erased flash of the given number of sectors if needed.
+    pub fn open(path: &Path, sectors: usize) -> io::Result<Self> {
+        let mut file = OpenOptions::new().read(true).write(true).create(true).truncate(false).open(path)?;
+        let capacity = sectors * Self::SECTOR_SIZE;
+
+        let length = file.metadata()?.len() as usize;
+        if length < capacity {
+            file.seek(SeekFrom::End(0))?;
+            file.write_all(&vec![0xFF; capacity - length])?;
+        }
+
+        Ok(Self {
+            file,
+            capacity,
+        })
+    }
+
+    fn read_at(&mut self, offset: u32, bytes: &mut [u8]) -> io::Result<()> {
+        self.file.seek(SeekFrom::Start(offset as u64))?;
+        self.file.read_exact(bytes)
+    }
+
+    fn write_at(&mut self, offset: u32, bytes: &[u8]) -> io::Result<()> {
+        self.file.seek(SeekFrom::Start(offset as u64))?;
+        self.file.write_all(bytes)?;
+        self.file.flush()
+    }
+}
+
+impl ErrorType for FileFlash {
+    type Error = NorFlashErrorKind;
+}
+
+impl ReadNorFlash for FileFlash {
+    const READ_SIZE: usize = 1;
+
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        check_read(self, offset, bytes.len())?;
+        self.read_at(offset, bytes).map_err(|_| NorFlashErrorKind::Other)
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+impl NorFlash for FileFlash {
+    const WRITE_SIZE: usize = 4;
+    const ERASE_SIZE: usize = Self::SECTOR_SIZE;
+
+    fn erase(&mut self, from: u32, to: u32) -> Result<(), Self::Error> {
+        check_erase(self, from, to)?;
+        self.write_at(from, &vec![0xFF; (to - from) as usize]).map_err(|_| NorFlashErrorKind::Other)
+    }
+
+    fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+        check_write(self, offset, bytes.len())?;
+
+        let mut current = vec![0; bytes.len()];
+        self.read_at(offset, &mut current).map_err(|_| NorFlashErrorKind::Other)?;
+        for (current, byte) in current.iter_mut().zip(bytes) {
+            *current &= byte;
+        }
+
+        self.write_at(offset, &current).map_err(|_| NorFlashErrorKind::Other)
+    }
+}
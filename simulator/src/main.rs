@@ -0,0 +1,204 @@
+mod file_flash;
+mod pty;
+mod renderer;
+mod serial;
+mod timer;
+
+use std::env;
+use std::io::{self, IsTerminal};
+use std::path::PathBuf;
+use std::process;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use embedded_hal::serial::{Read, Write};
+use nix::sys::termios::{self, SetArg, Termios};
+use smart_leds::RGB8;
+use map_core::animations::animation_manager::AnimationManager;
+use map_core::commands::command_handler::CommandHandler;
+use map_core::{commands, config, console, constants, map};
+use map_core::map::layout;
+use map_core::output::OutputSettings;
+use map_core::scenes::Scenes;
+use map_core::storage::flash_storage::FlashStorage;
+use map_core::storage::memory_storage::MemoryStorage;
+use map_core::storage::Storage;
+use crate::file_flash::FileFlash;
+use crate::pty::Pty;
+use crate::renderer::TerminalRenderer;
+use crate::serial::ThreadedSerial;
+use crate::timer::SystemTimer;
+
+const USAGE: &str = "Usage: map_simulator [--pty [LINK]] [--storage FILE]
+
+Runs the commands and animations of the board, drawing the LEDs to the terminal.
+The commands are read from the standard input, unless --pty is given.
+
+Options:
+  --pty [LINK]    serve the console on a new pseudo-terminal instead,
+                  like on the UART of the board. If LINK is given,
+                  a symlink to the pseudo-terminal is created there.
+  --storage FILE  keep the configuration and the scenes in FILE, emulating
+                  the flash of the board. They are lost on exit otherwise.";
+
+/// Sectors of the emulated flash, the same as the board uses.
+const STORAGE_SECTORS: usize = 6;
+
+/// Switches the terminal to raw mode so that the characters get to the
+/// command handler one by one, like from the UART, and restores it on drop.
+struct RawTerminal {
+    original: Termios,
+}
+
+impl RawTerminal {
+    fn enable() -> Option<Self> {
+        let stdin = io::stdin();
+        if !stdin.is_terminal() {
+            return None;
+        }
+
+        let original = termios::tcgetattr(&stdin).ok()?;
+        let mut raw = original.clone();
+        termios::cfmakeraw(&mut raw);
+        termios::tcsetattr(&stdin, SetArg::TCSANOW, &raw).ok()?;
+
+        Some(Self { original })
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        termios::tcsetattr(io::stdin(), SetArg::TCSANOW, &self.original).ok();
+    }
+}
+
+enum Console {
+    Stdio,
+    Pty(Option<PathBuf>),
+}
+
+struct Options {
+    console: Console,
+    storage: Option<PathBuf>,
+}
+
+fn parse_args() -> Result<Options, ()> {
+    let mut options = Options {
+        console: Console::Stdio,
+        storage: None,
+    };
+
+    let mut args = env::args().skip(1).peekable();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--pty" => {
+                let link = args.next_if(|arg| !arg.starts_with("--")).map(PathBuf::from);
+                options.console = Console::Pty(link);
+            }
+            "--storage" => options.storage = Some(args.next().map(PathBuf::from).ok_or(())?),
+            _ => return Err(()),
+        }
+    }
+
+    Ok(options)
+}
+
+fn open_storage(path: Option<PathBuf>) -> Box<dyn Storage> {
+    let path = match path {
+        Some(path) => path,
+        None => return Box::new(MemoryStorage::new()),
+    };
+
+    let flash = match FileFlash::open(&path, STORAGE_SECTORS) {
+        Ok(flash) => flash,
+        Err(err) => {
+            eprintln!("Could not open the storage {}: {}", path.display(), err);
+            process::exit(1);
+        }
+    };
+
+    match FlashStorage::new(flash, 0, STORAGE_SECTORS) {
+        Ok(storage) => Box::new(storage),
+        Err(err) => {
+            eprintln!("Could not load the storage {}: {:?}", path.display(), err);
+            process::exit(1);
+        }
+    }
+}
+
+fn main() {
+    let options = match parse_args() {
+        Ok(options) => options,
+        Err(()) => {
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    };
+
+    let mut storage = open_storage(options.storage);
+    let interrupted = Arc::new(AtomicBool::new(false));
+    match options.console {
+        Console::Stdio => {
+            let _terminal = RawTerminal::enable();
+            let mut serial = ThreadedSerial::stdio(interrupted.clone(), true);
+            let renderer = TerminalRenderer::new(layout::LAYOUT.districts);
+            run(&mut serial, renderer, storage.as_mut(), &interrupted, |serial| serial.is_closed());
+        }
+        Console::Pty(link) => {
+            let pty = match Pty::open(link) {
+                Ok(pty) => pty,
+                Err(err) => {
+                    eprintln!("Could not open a pseudo-terminal: {}", err);
+                    process::exit(1);
+                }
+            };
+            let mut serial = pty.serial().expect("The pseudo-terminal should be cloneable.");
+
+            let _terminal = RawTerminal::enable();
+            let _stdin = ThreadedSerial::stdio(interrupted.clone(), false);
+            let renderer = TerminalRenderer::new(layout::LAYOUT.districts);
+            print!("Console is available on {}\r\n", pty.path().display());
+            run(&mut serial, renderer, storage.as_mut(), &interrupted, |_| false);
+        }
+    }
+}
+
+/// The main loop of the board, running until interrupted or until
+/// the console is closed and no animation is left.
+fn run<Serial, F>(serial: &mut Serial, mut renderer: TerminalRenderer, storage: &mut dyn Storage, interrupted: &AtomicBool, is_closed: F)
+    where Serial: Read<u8> + Write<u8>, F: Fn(&Serial) -> bool
+{
+    // Init map
+    let mut rgb_data: [RGB8; constants::LEDS_COUNT] = [RGB8 { r: 0, g: 0, b: 0 }; constants::LEDS_COUNT];
+    let mut map = map::Map::new(&layout::LAYOUT, &mut rgb_data);
+    let mut settings = OutputSettings::new();
+    let mut scenes = Scenes::new();
+    config::load(storage, &mut map, &mut settings, &mut scenes);
+    let mut animations = AnimationManager::new(SystemTimer::new());
+
+    // Init commands
+    let mut handler = CommandHandler::new(
+        commands::handlers(),
+        ['\0'; constants::COMMAND_BUFFER],
+    );
+
+    console::print_new_command(serial);
+
+    loop {
+        let _ = animations.update(&mut map);
+
+        console::poll(&mut handler, serial, &mut map, animations.storage(), &mut settings, &mut scenes, storage);
+
+        // the terminal applies its own gamma, the frame is drawn without the color correction
+        renderer.render(settings.preview(map.frame()));
+
+        if interrupted.load(Ordering::Relaxed) || (is_closed(serial) && animations.storage().animation().is_none()) {
+            break;
+        }
+
+        thread::sleep(Duration::from_micros(500));
+    }
+
+    renderer.finish();
+}
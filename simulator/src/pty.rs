@@ -0,0 +1,70 @@
+use std::fs::{self, File};
+use std::io;
+use std::os::fd::{AsRawFd, OwnedFd};
+use std::os::unix::fs::symlink;
+use std::path::{Path, PathBuf};
+use nix::fcntl::{self, FcntlArg, OFlag};
+use nix::pty;
+use nix::sys::termios::{self, SetArg};
+use nix::unistd;
+use crate::serial::ThreadedSerial;
+
+/// Pseudo-terminal standing in for the UART0 of the board,
+/// clients connect to its slave side, e.g. `/dev/pts/3`.
+pub struct Pty {
+    master: File,
+    // Kept open so that the master does not fail on reads
+    // when no client is connected.
+    _slave: OwnedFd,
+    path: PathBuf,
+    link: Option<PathBuf>,
+}
+
+impl Pty {
+    /// Opens a new pseudo-terminal, if `link` is given, a symlink
+    /// to the slave is created there so that clients have a stable path.
+    pub fn open(link: Option<PathBuf>) -> io::Result<Self> {
+        let pty = pty::openpty(None, None)?;
+
+        // The slave passes the bytes through untouched, like a real UART.
+        let mut raw = termios::tcgetattr(&pty.slave)?;
+        termios::cfmakeraw(&mut raw);
+        termios::tcsetattr(&pty.slave, SetArg::TCSANOW, &raw)?;
+
+        // Output is dropped instead of blocking when no client reads it.
+        fcntl::fcntl(pty.master.as_raw_fd(), FcntlArg::F_SETFL(OFlag::O_NONBLOCK))?;
+
+        let path = unistd::ttyname(&pty.slave)?;
+        if let Some(link) = &link {
+            // replace a link left behind by a previous run
+            if fs::symlink_metadata(link).is_ok_and(|metadata| metadata.file_type().is_symlink()) {
+                fs::remove_file(link)?;
+            }
+            symlink(&path, link)?;
+        }
+
+        Ok(Self {
+            master: File::from(pty.master),
+            _slave: pty.slave,
+            path,
+            link,
+        })
+    }
+
+    /// Path clients should connect to.
+    pub fn path(&self) -> &Path {
+        self.link.as_deref().unwrap_or(&self.path)
+    }
+
+    pub fn serial(&self) -> io::Result<ThreadedSerial<File>> {
+        Ok(ThreadedSerial::new(self.master.try_clone()?, self.master.try_clone()?, Some))
+    }
+}
+
+impl Drop for Pty {
+    fn drop(&mut self) {
+        if let Some(link) = &self.link {
+            fs::remove_file(link).ok();
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use std::io::{self, Write};
+use smart_leds::RGB8;
+use map_core::map::district::{Bounds, District};
+
+const COLUMNS: usize = 60;
+const ROWS: usize = 26;
+
+/// First terminal row of the map, the row above it holds the title.
+const MAP_TOP: usize = 2;
+/// First terminal row of the console, everything below the map.
+const CONSOLE_TOP: usize = MAP_TOP + ROWS + 1;
+
+/// Draws the LEDs as true color blocks at their geographic positions
+/// in the top part of the terminal. The rest of the terminal is left
+/// as a scroll region for the console.
+pub struct TerminalRenderer {
+    cells: Vec<(usize, usize)>,
+    last_frame: Option<Vec<RGB8>>,
+}
+
+impl TerminalRenderer {
+    pub fn new(districts: &[District]) -> Self {
+        let bounds = Bounds::of(districts);
+        let mut cells: Vec<(usize, usize)> = Vec::with_capacity(districts.len());
+
+        for district in districts {
+            let (x, y) = bounds.normalize(district.coordinates);
+            let column = (x * (COLUMNS - 1) as f32).round() as isize;
+            let row = (y * (ROWS - 1) as f32).round() as isize;
+
+            cells.push(Self::free_cell(&cells, column, row));
+        }
+
+        let mut renderer = Self {
+            cells,
+            last_frame: None,
+        };
+        renderer.init();
+        renderer
+    }
+
+    /// Finds the nearest cell not taken by other district, close districts
+    /// would end up on the same cell otherwise.
+    fn free_cell(taken: &[(usize, usize)], column: isize, row: isize) -> (usize, usize) {
+        for distance in 0..COLUMNS as isize {
+            for d_row in -distance..=distance {
+                for d_column in -distance..=distance {
+                    if d_row.abs() != distance && d_column.abs() != distance {
+                        continue;
+                    }
+
+                    let (column, row) = (column + d_column, row + d_row);
+                    if column < 0 || row < 0 || column >= COLUMNS as isize || row >= ROWS as isize {
+                        continue;
+                    }
+
+                    let cell = (column as usize, row as usize);
+                    if !taken.contains(&cell) {
+                        return cell;
+                    }
+                }
+            }
+        }
+
+        panic!("The map does not fit to the terminal.");
+    }
+
+    fn init(&mut self) {
+        let mut stdout = io::stdout().lock();
+        write!(stdout, "\x1b[2J\x1b[1;1H\x1b[1mLED map simulator\x1b[0m (Ctrl-C to quit)").ok();
+        write!(stdout, "\x1b[{}r\x1b[{};1H", CONSOLE_TOP, CONSOLE_TOP).ok();
+        stdout.flush().ok();
+    }
+
+    pub fn render<T: Iterator<Item=RGB8>>(&mut self, frame: T) {
+        let frame: Vec<RGB8> = frame.collect();
+        if self.last_frame.as_ref() == Some(&frame) {
+            return;
+        }
+
+        let mut stdout = io::stdout().lock();
+        write!(stdout, "\x1b7").ok(); // save cursor of the console
+        for (&(column, row), rgb) in self.cells.iter().zip(frame.iter()) {
+            write!(stdout, "\x1b[{};{}H", MAP_TOP + row, 2 * column + 1).ok();
+            if rgb.r == 0 && rgb.g == 0 && rgb.b == 0 {
+                write!(stdout, "\x1b[38;2;60;60;60m··\x1b[0m").ok();
+            } else {
+                write!(stdout, "\x1b[38;2;{};{};{}m██\x1b[0m", rgb.r, rgb.g, rgb.b).ok();
+            }
+        }
+        write!(stdout, "\x1b8").ok();
+        stdout.flush().ok();
+
+        self.last_frame = Some(frame);
+    }
+
+    pub fn finish(&mut self) {
+        let mut stdout = io::stdout().lock();
+        write!(stdout, "\x1b7\x1b[r\x1b8\r\n").ok();
+        stdout.flush().ok();
+    }
+}
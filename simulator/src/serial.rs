@@ -0,0 +1,111 @@
+use std::convert::Infallible;
+use std::io::{self, ErrorKind, Read as IoRead, Stdout, Write as IoWrite};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use embedded_hal::serial::{Read, Write};
+use nb::Error::WouldBlock;
+
+/// Serial port reading from one stream and writing to another.
+///
+/// The input is read on a separate thread so that reading never blocks
+/// the main loop, the same way UART0 is polled on the board.
+pub struct ThreadedSerial<W> {
+    input: Receiver<u8>,
+    output: W,
+    closed: bool,
+}
+
+impl<W: IoWrite> ThreadedSerial<W> {
+    /// Every byte read from `input` is passed through `filter`,
+    /// the bytes it returns `None` for are dropped.
+    pub fn new<R, F>(input: R, output: W, mut filter: F) -> Self
+        where R: IoRead + Send + 'static, F: FnMut(u8) -> Option<u8> + Send + 'static
+    {
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            let mut input = input;
+            let mut buffer = [0u8; 64];
+            loop {
+                let read = match input.read(&mut buffer) {
+                    Ok(0) => break,
+                    Ok(read) => read,
+                    Err(err) if err.kind() == ErrorKind::WouldBlock || err.kind() == ErrorKind::Interrupted => {
+                        thread::sleep(Duration::from_millis(1));
+                        continue;
+                    }
+                    Err(_) => break
+                };
+
+                for byte in buffer[..read].iter().filter_map(|byte| filter(*byte)) {
+                    if sender.send(byte).is_err() {
+                        return;
+                    }
+                }
+            }
+        });
+
+        Self {
+            input: receiver,
+            output,
+            closed: false,
+        }
+    }
+
+    /// The input has ended, no more commands will be received.
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+}
+
+impl ThreadedSerial<Stdout> {
+    /// Serial on the standard input and output of the simulator.
+    ///
+    /// Ctrl-C and Ctrl-D set `interrupted`, when `forward` is false
+    /// nothing else gets to the serial and the input is only watched for them.
+    pub fn stdio(interrupted: Arc<AtomicBool>, forward: bool) -> Self {
+        Self::new(io::stdin(), io::stdout(), move |byte| match byte {
+            b'\x03' | b'\x04' => { // Ctrl-C, Ctrl-D in raw mode
+                interrupted.store(true, Ordering::Relaxed);
+                None
+            }
+            _ if !forward => None,
+            b'\n' => Some(b'\r'),
+            b'\x7f' => Some(b'\x08'), // terminals send DEL on backspace
+            byte => Some(byte)
+        })
+    }
+}
+
+impl<W> Read<u8> for ThreadedSerial<W> {
+    type Error = Infallible;
+
+    fn read(&mut self) -> nb::Result<u8, Self::Error> {
+        match self.input.try_recv() {
+            Ok(byte) => Ok(byte),
+            Err(TryRecvError::Disconnected) => {
+                self.closed = true;
+                Err(WouldBlock)
+            }
+            Err(TryRecvError::Empty) => Err(WouldBlock)
+        }
+    }
+}
+
+impl<W: IoWrite> Write<u8> for ThreadedSerial<W> {
+    type Error = Infallible;
+
+    fn write(&mut self, word: u8) -> nb::Result<(), Self::Error> {
+        self.output.write_all(&[word]).ok();
+        self.output.flush().ok();
+        Ok(())
+    }
+
+    fn flush(&mut self) -> nb::Result<(), Self::Error> {
+        self.output.flush().ok();
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+use std::time::{Duration, Instant};
+use embedded_hal::timer::CountDown;
+use fugit::MicrosDurationU64;
+use nb::Error::WouldBlock;
+use void::Void;
+
+/// Count down timer backed by the system clock,
+/// stands in for the timer group timer of the ESP32.
+#[derive(Default)]
+pub struct SystemTimer {
+    deadline: Option<Instant>,
+}
+
+impl SystemTimer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl CountDown for SystemTimer {
+    type Time = MicrosDurationU64;
+
+    fn start<T>(&mut self, count: T) where T: Into<Self::Time> {
+        let duration = Duration::from_micros(count.into().ticks());
+        self.deadline = Some(Instant::now() + duration);
+    }
+
+    fn wait(&mut self) -> nb::Result<(), Void> {
+        match self.deadline {
+            Some(deadline) if Instant::now() >= deadline => {
+                self.deadline = None;
+                Ok(())
+            }
+            _ => Err(WouldBlock)
+        }
+    }
+}
@@ -1,9 +0,0 @@
-pub mod hello_world_command;
-pub mod command_handler;
-pub mod command;
-pub mod command_argument;
-pub mod set_command;
-pub mod reset_command;
-pub mod all_command;
-pub mod command_data;
-pub mod snake_command;
\ No newline at end of file
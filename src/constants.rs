@@ -1,2 +0,0 @@
-pub const LEDS_COUNT: usize = 72;
-pub const COMMAND_BUFFER: usize = 200;
\ No newline at end of file